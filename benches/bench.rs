@@ -2,11 +2,17 @@ use criterion::{
     criterion_group, criterion_main, measurement::Measurement, BatchSize, BenchmarkGroup, Criterion,
 };
 use crypto_bigint::{
-    modular::runtime_mod::{DynResidue, DynResidueParams},
-    Limb, MultiExponentiate, NonZero, Random, Reciprocal, U128, U2048, U256,
+    modular::{
+        runtime_mod::{DynResidue, DynResidueParams},
+        Window, Window1, Window2, Window4, Window5, Window6,
+    },
+    Limb, NonZero, Random, Reciprocal, Uint, U128, U2048, U256, U4096,
 };
 use rand_core::OsRng;
 
+#[cfg(feature = "alloc")]
+use crypto_bigint::MultiExponentiate;
+
 fn bench_division<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
     group.bench_function("div/rem, U256/U128, full size", |b| {
         b.iter_batched(
@@ -34,6 +40,71 @@ fn bench_division<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
         )
     });
 
+    group.bench_function("div/rem_vartime, U256/U128, full size", |b| {
+        b.iter_batched(
+            || {
+                let x = U256::random(&mut OsRng);
+                let y_half = U128::random(&mut OsRng);
+                let y: U256 = (y_half, U128::ZERO).into();
+                (x, NonZero::new(y).unwrap())
+            },
+            |(x, y)| x.div_rem_vartime(&y),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("rem_vartime, U256/U128, full size", |b| {
+        b.iter_batched(
+            || {
+                let x = U256::random(&mut OsRng);
+                let y_half = U128::random(&mut OsRng);
+                let y: U256 = (y_half, U128::ZERO).into();
+                (x, NonZero::new(y).unwrap())
+            },
+            |(x, y)| x.rem_vartime(&y),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("rem, U4096/U2048, full size", |b| {
+        b.iter_batched(
+            || {
+                let x = U4096::random(&mut OsRng);
+                let y_half = U2048::random(&mut OsRng);
+                let y: U4096 = (y_half, U2048::ZERO).into();
+                (x, NonZero::new(y).unwrap())
+            },
+            |(x, y)| x.rem(&y),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("rem_vartime, U4096/U2048, full size", |b| {
+        b.iter_batched(
+            || {
+                let x = U4096::random(&mut OsRng);
+                let y_half = U2048::random(&mut OsRng);
+                let y: U4096 = (y_half, U2048::ZERO).into();
+                (x, NonZero::new(y).unwrap())
+            },
+            |(x, y)| x.rem_vartime(&y),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("const_rem_wide, U2048", |b| {
+        b.iter_batched(
+            || {
+                let lo = U2048::random(&mut OsRng);
+                let hi = U2048::random(&mut OsRng);
+                let m = U2048::random(&mut OsRng) | U2048::ONE;
+                ((lo, hi), m)
+            },
+            |(x, m)| U2048::const_rem_wide(x, &m),
+            BatchSize::SmallInput,
+        )
+    });
+
     group.bench_function("div/rem, U256/Limb, full size", |b| {
         b.iter_batched(
             || {
@@ -73,6 +144,24 @@ fn bench_division<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
     });
 }
 
+fn bench_mul<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
+    group.bench_function("mul_wide, U4096*U4096", |b| {
+        b.iter_batched(
+            || (U4096::random(&mut OsRng), U4096::random(&mut OsRng)),
+            |(x, y)| x.mul_wide(&y),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("square_wide, U4096", |b| {
+        b.iter_batched(
+            || U4096::random(&mut OsRng),
+            |x| x.square_wide(),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
 fn bench_montgomery_ops<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
     let params = DynResidueParams::new(&(U256::random(&mut OsRng) | U256::ONE));
     group.bench_function("multiplication, U256*U256", |b| {
@@ -87,6 +176,14 @@ fn bench_montgomery_ops<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
         )
     });
 
+    group.bench_function("squaring, U256*U256", |b| {
+        b.iter_batched(
+            || DynResidue::new(&U256::random(&mut OsRng), params),
+            |x| x.square(),
+            BatchSize::SmallInput,
+        )
+    });
+
     let m = U256::random(&mut OsRng) | U256::ONE;
     let params = DynResidueParams::new(&m);
     group.bench_function("modpow, U256^U256", |b| {
@@ -102,6 +199,7 @@ fn bench_montgomery_ops<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
         )
     });
 
+    #[cfg(feature = "alloc")]
     for i in [1, 2, 3, 4, 10, 100] {
         group.bench_function(
             format!("multi_exponentiate for {i} bases, U256^U256"),
@@ -129,6 +227,75 @@ fn bench_montgomery_ops<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
             },
         );
     }
+
+    #[cfg(feature = "alloc")]
+    for i in [1, 10, 100, 1000] {
+        group.bench_function(
+            format!("multi_exponentiate_vartime for {i} bases, U256^U256"),
+            |b| {
+                b.iter_batched(
+                    || {
+                        let bases_and_exponents: Vec<(DynResidue<{ U256::LIMBS }>, U256)> = (1..=i)
+                            .map(|_| {
+                                let x = U256::random(&mut OsRng);
+                                let x_m = DynResidue::new(&x, params);
+                                let p = U256::random(&mut OsRng) | (U256::ONE << (U256::BITS - 1));
+                                (x_m, p)
+                            })
+                            .collect();
+
+                        bases_and_exponents
+                    },
+                    |bases_and_exponents| {
+                        DynResidue::<{ U256::LIMBS }>::multi_exponentiate_vartime(
+                            bases_and_exponents.as_slice(),
+                        )
+                    },
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+}
+
+fn bench_pow_with_window<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
+    fn bench<M: Measurement, const LIMBS: usize, W: Window>(group: &mut BenchmarkGroup<'_, M>) {
+        group.bench_function(
+            format!(
+                "modpow with {}-bit window, U{1}^U{1}",
+                W::BITS,
+                Uint::<LIMBS>::BITS
+            ),
+            |b| {
+                b.iter_batched(
+                    || {
+                        let m = Uint::<LIMBS>::random(&mut OsRng) | Uint::ONE;
+                        let params = DynResidueParams::new(&m);
+                        let x = Uint::<LIMBS>::random(&mut OsRng);
+                        let x_m = DynResidue::new(&x, params);
+                        let p = Uint::<LIMBS>::random(&mut OsRng)
+                            | (Uint::ONE << (Uint::<LIMBS>::BITS - 1));
+                        (x_m, p)
+                    },
+                    |(x, p)| x.pow_with_window::<W>(&p),
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+
+    bench::<M, { U256::LIMBS }, Window1>(group);
+    bench::<M, { U256::LIMBS }, Window2>(group);
+    bench::<M, { U256::LIMBS }, Window4>(group);
+    bench::<M, { U256::LIMBS }, Window5>(group);
+
+    bench::<M, { U2048::LIMBS }, Window4>(group);
+    bench::<M, { U2048::LIMBS }, Window5>(group);
+    bench::<M, { U2048::LIMBS }, Window6>(group);
+
+    bench::<M, { U4096::LIMBS }, Window4>(group);
+    bench::<M, { U4096::LIMBS }, Window5>(group);
+    bench::<M, { U4096::LIMBS }, Window6>(group);
 }
 
 fn bench_montgomery_conversion<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
@@ -236,6 +403,7 @@ fn bench_inv_mod<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
 
 fn bench_wrapping_ops(c: &mut Criterion) {
     let mut group = c.benchmark_group("wrapping ops");
+    bench_mul(&mut group);
     bench_division(&mut group);
     group.finish();
 }
@@ -244,6 +412,7 @@ fn bench_montgomery(c: &mut Criterion) {
     let mut group = c.benchmark_group("Montgomery arithmetic");
     bench_montgomery_conversion(&mut group);
     bench_montgomery_ops(&mut group);
+    bench_pow_with_window(&mut group);
     group.finish();
 }
 
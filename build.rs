@@ -0,0 +1,5 @@
+fn main() {
+    // Declare the `sidefuzz` cfg so it isn't reported as unexpected by newer toolchains.
+    // The single-colon syntax keeps this compatible with older Cargo versions.
+    println!("cargo:rustc-check-cfg=cfg(sidefuzz)");
+}
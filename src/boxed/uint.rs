@@ -1,11 +1,23 @@
 //! Heap-allocated big unsigned integers.
 
 mod add;
+mod bits;
 mod cmp;
-
-use crate::{Limb, Word};
+mod div;
+mod encoding;
+mod mul;
+mod neg;
+mod shl;
+mod shr;
+mod sub;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+use crate::{CtChoice, Limb, UintLike, Unsigned, Word, Zero};
 use alloc::{vec, vec::Vec};
 use core::fmt;
+use subtle::Choice;
 
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
@@ -137,30 +149,90 @@ impl BoxedUint {
         }
     }
 
-    /// Perform a carry chain-like operation over the limbs of the inputs,
-    /// constructing a result from the returned limbs and carry.
+    /// Computes `shift % self.bits()` with a bit-by-bit long division, so that unlike
+    /// the `%` operator with a runtime divisor it is constant-time with respect to `shift`.
     ///
-    /// If one of the two values has fewer limbs than the other, passes
-    /// [`Limb::ZERO`] as the value for that limb.
-    fn chain<F>(a: &Self, b: &Self, mut carry: Limb, f: F) -> (Self, Limb)
-    where
-        F: Fn(Limb, Limb, Limb) -> (Limb, Limb),
-    {
-        let (shorter, longer) = Self::sort_by_precision(a, b);
-        let mut limbs = Vec::with_capacity(longer.limbs.len());
-
-        for i in 0..longer.limbs.len() {
-            let &a = shorter.limbs.get(i).unwrap_or(&Limb::ZERO);
-            let &b = longer.limbs.get(i).unwrap_or(&Limb::ZERO);
-            let (limb, c) = f(a, b, carry);
-            limbs.push(limb);
-            carry = c;
+    /// `self.bits()` must be nonzero.
+    fn reduce_shift(&self, shift: usize) -> usize {
+        let bits = self.bits();
+        debug_assert!(bits > 0);
+
+        let mut rem = 0usize;
+        for i in (0..usize::BITS).rev() {
+            rem = (rem << 1) | ((shift >> i) & 1);
+            let reduce = CtChoice::from_usize_lt(rem, bits).not();
+            rem = reduce.select(rem as Word, rem.wrapping_sub(bits) as Word) as usize;
         }
+        rem
+    }
+}
+
+impl UintLike for BoxedUint {
+    fn bits_precision(&self) -> usize {
+        self.bits()
+    }
 
-        (Self { limbs }, carry)
+    fn nlimbs(&self) -> usize {
+        self.limbs.len()
+    }
+
+    fn zero_like(&self) -> Self {
+        Self {
+            limbs: vec![Limb::ZERO; self.limbs.len()],
+        }
+    }
+
+    fn one_like(&self) -> Self {
+        Self::from_word_like(1, self)
     }
 }
 
+impl Unsigned for BoxedUint {
+    fn from_word_like(word: Word, other: &Self) -> Self {
+        let mut ret = other.zero_like();
+        if let Some(limb) = ret.limbs.first_mut() {
+            *limb = Limb(word);
+        }
+        ret
+    }
+
+    fn is_odd(&self) -> Choice {
+        self.is_odd()
+    }
+
+    fn bit_vartime(&self, index: usize) -> bool {
+        self.bit_vartime(index)
+    }
+
+    fn bits_vartime(&self) -> usize {
+        self.bits_vartime()
+    }
+
+    fn wrapping_add(&self, rhs: &Self) -> Self {
+        self.wrapping_add(rhs)
+    }
+
+    fn wrapping_sub(&self, rhs: &Self) -> Self {
+        self.wrapping_sub(rhs)
+    }
+
+    fn wrapping_mul(&self, rhs: &Self) -> Self {
+        self.wrapping_mul(rhs)
+    }
+
+    fn shl_vartime(&self, shift: usize) -> Self {
+        self.shl_vartime(shift)
+    }
+
+    fn shr_vartime(&self, shift: usize) -> Self {
+        self.shr_vartime(shift)
+    }
+}
+
+impl Zero for BoxedUint {
+    const ZERO: Self = Self { limbs: Vec::new() };
+}
+
 impl AsRef<[Word]> for BoxedUint {
     fn as_ref(&self) -> &[Word] {
         self.as_words()
@@ -223,6 +295,18 @@ impl fmt::UpperHex for BoxedUint {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for BoxedUint {
+    /// Generate a [`BoxedUint`] with an arbitrary, non-zero number of limbs.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut limbs = u.arbitrary::<Vec<Limb>>()?;
+        if limbs.is_empty() {
+            limbs.push(u.arbitrary()?);
+        }
+        Ok(Self { limbs })
+    }
+}
+
 #[cfg(feature = "zeroize")]
 impl Zeroize for BoxedUint {
     fn zeroize(&mut self) {
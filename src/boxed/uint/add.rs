@@ -1,19 +1,30 @@
 //! [`BoxedUint`] addition operations.
 
-use crate::{BoxedUint, CheckedAdd, Limb, Zero};
+use crate::{limb_slice, BoxedUint, CheckedAdd, CtChoice, Limb, Zero};
+use core::ops::Add;
 use subtle::CtOption;
 
 impl BoxedUint {
     /// Computes `a + b + carry`, returning the result along with the new carry.
     #[inline(always)]
     pub fn adc(&self, rhs: &Self, carry: Limb) -> (Self, Limb) {
-        Self::chain(self, rhs, carry, |a, b, c| a.adc(b, c))
+        let (shorter, longer) = Self::sort_by_precision(self, rhs);
+        let mut res = longer.clone();
+        let carry = limb_slice::add_n(&mut res.limbs, &shorter.limbs, carry);
+        (res, carry)
     }
 
     /// Perform wrapping addition, discarding overflow.
     pub fn wrapping_add(&self, rhs: &Self) -> Self {
         self.adc(rhs, Limb::ZERO).0
     }
+
+    /// Computes `self + rhs`, returning the result along with [`CtChoice::TRUE`]
+    /// if an overflow occurred.
+    pub fn overflowing_add(&self, rhs: &Self) -> (Self, CtChoice) {
+        let (res, carry) = self.adc(rhs, Limb::ZERO);
+        (res, CtChoice::from_lsb(carry.0))
+    }
 }
 
 impl CheckedAdd<&BoxedUint> for BoxedUint {
@@ -25,6 +36,23 @@ impl CheckedAdd<&BoxedUint> for BoxedUint {
     }
 }
 
+impl Add for BoxedUint {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.add(&rhs)
+    }
+}
+
+impl Add<&BoxedUint> for BoxedUint {
+    type Output = Self;
+
+    fn add(self, rhs: &Self) -> Self {
+        self.checked_add(rhs)
+            .expect("attempted to add with overflow")
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -59,4 +87,28 @@ mod tests {
             .checked_add(&BoxedUint::one());
         assert!(!bool::from(result.is_some()));
     }
+
+    #[test]
+    fn add_ok() {
+        assert_eq!(BoxedUint::zero() + BoxedUint::one(), BoxedUint::one());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to add with overflow")]
+    fn add_overflow() {
+        let _ = BoxedUint::max(Limb::BITS).unwrap() + &BoxedUint::one();
+    }
+
+    #[test]
+    fn overflowing_add() {
+        let (res, overflow) = BoxedUint::one().overflowing_add(&BoxedUint::one());
+        assert_eq!(res, BoxedUint::from_words(&[2]));
+        assert!(!bool::from(overflow));
+
+        let (res, overflow) = BoxedUint::max(Limb::BITS)
+            .unwrap()
+            .overflowing_add(&BoxedUint::one());
+        assert_eq!(res, BoxedUint::zero());
+        assert!(bool::from(overflow));
+    }
 }
@@ -0,0 +1,62 @@
+//! [`BoxedUint`] bit operations.
+
+use crate::{BoxedUint, Limb};
+use subtle::Choice;
+
+impl BoxedUint {
+    /// Returns `true` if the bit at position `index` is set, `false` otherwise.
+    ///
+    /// # Remarks
+    /// This operation is variable time with respect to `index` only.
+    pub fn bit_vartime(&self, index: usize) -> bool {
+        self.limbs
+            .get(index / Limb::BITS)
+            .map_or(false, |limb| (limb.0 >> (index % Limb::BITS)) & 1 == 1)
+    }
+
+    /// Calculate the number of bits needed to represent this number.
+    pub fn bits_vartime(&self) -> usize {
+        match self.limbs.iter().rposition(|limb| limb.0 != 0) {
+            Some(i) => Limb::BITS * (i + 1) - self.limbs[i].leading_zeros(),
+            None => 0,
+        }
+    }
+
+    /// Is this integer value an odd number?
+    pub fn is_odd(&self) -> Choice {
+        self.limbs
+            .first()
+            .map_or_else(|| Choice::from(0), |limb| limb.is_odd())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedUint, Limb};
+
+    #[test]
+    fn bit_vartime() {
+        let n = BoxedUint::from_words(&[0b101, 1]);
+        assert!(n.bit_vartime(0));
+        assert!(!n.bit_vartime(1));
+        assert!(n.bit_vartime(2));
+        assert!(n.bit_vartime(Limb::BITS));
+        assert!(!n.bit_vartime(2 * Limb::BITS));
+    }
+
+    #[test]
+    fn bits_vartime() {
+        assert_eq!(BoxedUint::from_words(&[0, 0]).bits_vartime(), 0);
+        assert_eq!(BoxedUint::from_words(&[1, 0]).bits_vartime(), 1);
+        assert_eq!(
+            BoxedUint::from_words(&[0, 3]).bits_vartime(),
+            Limb::BITS + 2
+        );
+    }
+
+    #[test]
+    fn is_odd() {
+        assert!(!bool::from(BoxedUint::from_words(&[2, 1]).is_odd()));
+        assert!(bool::from(BoxedUint::from_words(&[3, 0]).is_odd()));
+    }
+}
@@ -3,8 +3,33 @@
 //! By default these are all constant-time and use the `subtle` crate.
 
 use super::BoxedUint;
-use crate::Limb;
-use subtle::{Choice, ConstantTimeEq};
+use crate::{limb_slice, CtChoice, Limb};
+use core::cmp::Ordering;
+use subtle::{Choice, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
+
+impl BoxedUint {
+    /// Return `b` if `c` is truthy, otherwise return `a`.
+    ///
+    /// Both operands must have the same precision.
+    #[inline]
+    pub(crate) fn ct_select(a: &Self, b: &Self, c: CtChoice) -> Self {
+        debug_assert_eq!(a.limbs.len(), b.limbs.len());
+        Self {
+            limbs: a
+                .limbs
+                .iter()
+                .zip(b.limbs.iter())
+                .map(|(a, b)| Limb::ct_select(*a, *b, c))
+                .collect(),
+        }
+    }
+
+    /// Returns the truthy value if `self` is not zero.
+    #[inline]
+    pub(crate) fn ct_is_nonzero(&self) -> CtChoice {
+        Limb(self.limbs.iter().fold(0, |acc, limb| acc | limb.0)).ct_is_nonzero()
+    }
+}
 
 impl ConstantTimeEq for BoxedUint {
     #[inline]
@@ -22,6 +47,22 @@ impl ConstantTimeEq for BoxedUint {
     }
 }
 
+impl ConstantTimeGreater for BoxedUint {
+    #[inline]
+    fn ct_gt(&self, other: &Self) -> Choice {
+        let (_, borrow) = other.sbb(self, Limb::ZERO);
+        Choice::from((borrow.0 & 1) as u8)
+    }
+}
+
+impl ConstantTimeLess for BoxedUint {
+    #[inline]
+    fn ct_lt(&self, other: &Self) -> Choice {
+        let (_, borrow) = self.sbb(other, Limb::ZERO);
+        Choice::from((borrow.0 & 1) as u8)
+    }
+}
+
 impl Eq for BoxedUint {}
 impl PartialEq for BoxedUint {
     fn eq(&self, other: &Self) -> bool {
@@ -29,10 +70,24 @@ impl PartialEq for BoxedUint {
     }
 }
 
+impl Ord for BoxedUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        limb_slice::cmp_n(&self.limbs, &other.limbs)
+    }
+}
+
+impl PartialOrd for BoxedUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
     use super::BoxedUint;
-    use subtle::ConstantTimeEq;
+    use core::cmp::Ordering;
+    use subtle::{ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
 
     #[test]
     fn ct_eq() {
@@ -44,4 +99,35 @@ mod tests {
         assert!(!bool::from(b.ct_eq(&a)));
         assert!(bool::from(b.ct_eq(&b)));
     }
+
+    #[test]
+    fn ct_gt_lt() {
+        let a = BoxedUint::zero();
+        let b = BoxedUint::one();
+        let c = BoxedUint::max(256).unwrap();
+
+        assert!(bool::from(b.ct_gt(&a)));
+        assert!(bool::from(c.ct_gt(&b)));
+        assert!(!bool::from(a.ct_gt(&b)));
+        assert!(!bool::from(b.ct_gt(&b)));
+
+        assert!(bool::from(a.ct_lt(&b)));
+        assert!(bool::from(b.ct_lt(&c)));
+        assert!(!bool::from(c.ct_lt(&b)));
+        assert!(!bool::from(b.ct_lt(&b)));
+    }
+
+    #[test]
+    fn cmp() {
+        let a = BoxedUint::zero();
+        let b = BoxedUint::one();
+        let c = BoxedUint::max(256).unwrap();
+
+        assert_eq!(a.cmp(&b), Ordering::Less);
+        assert_eq!(c.cmp(&b), Ordering::Greater);
+        assert_eq!(
+            b.cmp(&BoxedUint::new(128).unwrap().wrapping_add(&b)),
+            Ordering::Equal
+        );
+    }
 }
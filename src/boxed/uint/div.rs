@@ -0,0 +1,116 @@
+//! [`BoxedUint`] division operations.
+
+use crate::{limb_slice, BoxedUint, CtChoice, Limb, NonZero, UintLike};
+use core::ops::{Div, Rem};
+
+impl BoxedUint {
+    /// Computes `self` / `rhs`, returns the quotient (q) and remainder (r).
+    ///
+    /// Both operands must have the same precision.
+    ///
+    /// This is variable-time only with respect to `rhs`.
+    ///
+    /// When used with a fixed `rhs`, this function is constant-time with respect
+    /// to `self`.
+    pub fn div_rem(&self, rhs: &NonZero<Self>) -> (Self, Self) {
+        assert_eq!(
+            self.limbs.len(),
+            rhs.limbs.len(),
+            "operands must have the same precision"
+        );
+
+        let mb = rhs.bits_vartime();
+        let mut bd = self.bits_precision() - mb;
+        let mut rem = self.clone();
+        let mut quo = self.zero_like();
+
+        // `c = rhs << bd`, shifted in place by whole limbs and then by the remaining bits
+        let mut c = rhs.as_ref().clone();
+        let limb_shift = bd / Limb::BITS;
+        let nlimbs = c.limbs.len();
+        c.limbs.copy_within(..nlimbs - limb_shift, limb_shift);
+        c.limbs[..limb_shift].fill(Limb::ZERO);
+        limb_slice::shl_n(&mut c.limbs, bd % Limb::BITS);
+
+        loop {
+            // Subtract `c`, and add it back if the subtraction underflowed
+            let borrow = limb_slice::sub_n(&mut rem.limbs, &c.limbs, Limb::ZERO);
+            limb_slice::conditional_add_n(&mut rem.limbs, &c.limbs, CtChoice::from_mask(borrow.0));
+            quo.limbs[0] = quo.limbs[0].bitor(Limb(!borrow.0 & 1));
+            if bd == 0 {
+                break;
+            }
+            bd -= 1;
+            limb_slice::shr_n(&mut c.limbs, 1);
+            limb_slice::shl_n(&mut quo.limbs, 1);
+        }
+
+        (quo, rem)
+    }
+
+    /// Computes `self` % `rhs`.
+    ///
+    /// Both operands must have the same precision.
+    ///
+    /// This is variable-time only with respect to `rhs`.
+    pub fn rem(&self, rhs: &NonZero<Self>) -> Self {
+        self.div_rem(rhs).1
+    }
+}
+
+impl Div<NonZero<BoxedUint>> for BoxedUint {
+    type Output = BoxedUint;
+
+    fn div(self, rhs: NonZero<BoxedUint>) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl Div<&NonZero<BoxedUint>> for &BoxedUint {
+    type Output = BoxedUint;
+
+    fn div(self, rhs: &NonZero<BoxedUint>) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem<NonZero<BoxedUint>> for BoxedUint {
+    type Output = BoxedUint;
+
+    fn rem(self, rhs: NonZero<BoxedUint>) -> Self::Output {
+        BoxedUint::rem(&self, &rhs)
+    }
+}
+
+impl Rem<&NonZero<BoxedUint>> for &BoxedUint {
+    type Output = BoxedUint;
+
+    fn rem(self, rhs: &NonZero<BoxedUint>) -> Self::Output {
+        BoxedUint::rem(self, rhs)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{BoxedUint, NonZero};
+
+    #[test]
+    fn div_rem() {
+        let n = BoxedUint::from_words(&[0x0123_4567, 0x89ab_cdef]);
+        let d = NonZero::new(BoxedUint::from_words(&[0x1234, 0])).unwrap();
+        let (q, r) = n.div_rem(&d);
+        assert!(r < *d);
+        assert_eq!(q.wrapping_mul(&d).wrapping_add(&r), n);
+        assert_eq!(&n / &d, q);
+        assert_eq!(&n % &d, r);
+    }
+
+    #[test]
+    fn div_rem_by_self() {
+        let n = BoxedUint::from_words(&[0, 1]);
+        let (q, r) = n.div_rem(&NonZero::new(n.clone()).unwrap());
+        assert_eq!(q, BoxedUint::from_words(&[1, 0]));
+        assert_eq!(r, BoxedUint::from_words(&[0, 0]));
+    }
+}
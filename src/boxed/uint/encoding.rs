@@ -0,0 +1,151 @@
+//! [`BoxedUint`] encoding operations.
+
+#[cfg(feature = "der")]
+mod der;
+
+use crate::{limb_slice, BoxedUint, CtChoice};
+use subtle::{Choice, ConstantTimeEq, CtOption};
+
+#[cfg(any(feature = "borsh", feature = "parity-scale-codec", feature = "serde"))]
+use {
+    crate::{Limb, Word},
+    alloc::vec::Vec,
+};
+
+impl BoxedUint {
+    /// Serialize this [`BoxedUint`] as big-endian without leading zeros, writing it into the
+    /// beginning of `out` and returning the number of bytes written.
+    ///
+    /// Zero is encoded as an empty string.
+    ///
+    /// NOTE: the length of the encoding is leaked in the time pattern,
+    /// use [`BoxedUint::to_be_bytes_padded`] when it must stay secret.
+    ///
+    /// # Panics
+    ///
+    /// If `out` is shorter than the encoding.
+    pub fn to_be_bytes_minimal(&self, out: &mut [u8]) -> usize {
+        limb_slice::to_be_bytes_minimal(&self.limbs, out)
+    }
+
+    /// Decode a [`BoxedUint`] with the given precision from big-endian bytes without
+    /// leading zeros, as produced by [`BoxedUint::to_be_bytes_minimal`].
+    ///
+    /// Returns `None` if `bytes` starts with a zero byte, the value doesn't fit,
+    /// or `bits_precision` is not a multiple of the [`Limb`][`crate::Limb`] size.
+    pub fn from_be_bytes_minimal(bytes: &[u8], bits_precision: usize) -> CtOption<Self> {
+        let leading_zero = bytes.first().map_or(0.into(), |byte| byte.ct_eq(&0));
+        Self::decode_be_bytes(bytes, bits_precision, !leading_zero)
+    }
+
+    /// Serialize this [`BoxedUint`] as big-endian, left-padded with zeros to the length of `out`,
+    /// in constant time.
+    ///
+    /// Returns a falsy [`CtChoice`] if the value doesn't fit in `out`,
+    /// in which case its most significant bytes are dropped.
+    pub fn to_be_bytes_padded(&self, out: &mut [u8]) -> CtChoice {
+        limb_slice::to_be_bytes_padded(&self.limbs, out)
+    }
+
+    /// Decode a [`BoxedUint`] with the given precision from big-endian bytes of any length,
+    /// in constant time.
+    ///
+    /// Leading zeros are accepted. Returns `None` if the value doesn't fit,
+    /// or `bits_precision` is not a multiple of the [`Limb`][`crate::Limb`] size.
+    pub fn from_be_bytes_padded(bytes: &[u8], bits_precision: usize) -> CtOption<Self> {
+        Self::decode_be_bytes(bytes, bits_precision, Choice::from(1))
+    }
+
+    /// Serialize this [`BoxedUint`] as the little endian bytes of its full precision.
+    #[cfg(any(feature = "borsh", feature = "parity-scale-codec", feature = "serde"))]
+    pub(crate) fn to_le_byte_vec(&self) -> Vec<u8> {
+        self.as_words()
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect()
+    }
+
+    /// Decode a [`BoxedUint`] with the given precision from little endian `bytes`,
+    /// which must not be longer than the precision.
+    #[cfg(any(feature = "borsh", feature = "parity-scale-codec", feature = "serde"))]
+    pub(crate) fn from_le_byte_slice(bytes: &[u8], bits_precision: usize) -> Option<Self> {
+        let mut uint = Self::new(bits_precision)?;
+        if bytes.len() * 8 > bits_precision {
+            return None;
+        }
+
+        for (i, byte) in bytes.iter().enumerate() {
+            uint.limbs[i / Limb::BYTES].0 |= (*byte as Word) << ((i % Limb::BYTES) * 8);
+        }
+
+        Some(uint)
+    }
+
+    fn decode_be_bytes(bytes: &[u8], bits_precision: usize, is_valid: Choice) -> CtOption<Self> {
+        match Self::new(bits_precision) {
+            Some(mut uint) => {
+                let fits = limb_slice::from_be_bytes_padded(&mut uint.limbs, bytes);
+                CtOption::new(uint, Choice::from(fits) & is_valid)
+            }
+            None => CtOption::new(Self::zero(), Choice::from(0)),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::BoxedUint;
+    use hex_literal::hex;
+
+    #[test]
+    fn be_bytes_minimal() {
+        let n = BoxedUint::from_words(&[0x0102, 0, 0, 0]);
+        let mut out = [0xffu8; 32];
+        let len = n.to_be_bytes_minimal(&mut out);
+        assert_eq!(&out[..len], &hex!("0102"));
+        assert_eq!(
+            BoxedUint::from_be_bytes_minimal(&out[..len], n.bits())
+                .unwrap()
+                .as_words(),
+            n.as_words()
+        );
+
+        let zero = BoxedUint::new(128).unwrap();
+        assert_eq!(zero.to_be_bytes_minimal(&mut out), 0);
+        assert_eq!(
+            BoxedUint::from_be_bytes_minimal(&[], 128)
+                .unwrap()
+                .as_words(),
+            &[0; 128 / crate::Limb::BITS]
+        );
+
+        assert!(bool::from(
+            BoxedUint::from_be_bytes_minimal(&hex!("000102"), 128).is_none()
+        ));
+        assert!(bool::from(
+            BoxedUint::from_be_bytes_minimal(&[1; 17], 128).is_none()
+        ));
+        assert!(bool::from(
+            BoxedUint::from_be_bytes_minimal(&hex!("01"), 100).is_none()
+        ));
+    }
+
+    #[test]
+    fn be_bytes_padded() {
+        let n = BoxedUint::from_words(&[0x0102, 0, 0, 0]);
+        let mut out = [0xffu8; 4];
+        assert!(n.to_be_bytes_padded(&mut out).is_true_vartime());
+        assert_eq!(out, hex!("00000102"));
+
+        let mut out = [0xffu8; 1];
+        assert!(!n.to_be_bytes_padded(&mut out).is_true_vartime());
+        assert_eq!(out, hex!("02"));
+
+        let decoded = BoxedUint::from_be_bytes_padded(&[0; 40], 64).unwrap();
+        assert_eq!(decoded.as_words(), &[0; 64 / crate::Limb::BITS]);
+        assert!(bool::from(
+            BoxedUint::from_be_bytes_padded(&hex!("010000000000000000"), 64).is_none()
+        ));
+    }
+}
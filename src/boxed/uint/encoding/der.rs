@@ -0,0 +1,125 @@
+//! Support for decoding/encoding [`BoxedUint`] as an ASN.1 DER `INTEGER`.
+
+use crate::{BoxedUint, Limb};
+use ::der::{
+    asn1::{AnyRef, UintRef},
+    Decode, DecodeValue, EncodeValue, FixedTag, Length, Tag,
+};
+use alloc::{vec, vec::Vec};
+
+impl BoxedUint {
+    /// Decode a DER-encoded `INTEGER` into a [`BoxedUint`] with the given precision.
+    ///
+    /// Returns an error if the value doesn't fit, or if `bits_precision` is not a multiple
+    /// of the [`Limb`] size.
+    pub fn from_der_with_precision(bytes: &[u8], bits_precision: usize) -> der::Result<Self> {
+        Self::from_uint_ref(UintRef::from_der(bytes)?, bits_precision)
+    }
+
+    /// Precision needed to hold any value encoded in `len` bytes.
+    fn der_precision(len: usize) -> usize {
+        let limbs = (len + Limb::BYTES - 1) / Limb::BYTES;
+        limbs.max(1) * Limb::BITS
+    }
+
+    fn from_uint_ref(bytes: UintRef<'_>, bits_precision: usize) -> der::Result<Self> {
+        if Self::new(bits_precision).is_none() {
+            return Err(Tag::Integer.value_error());
+        }
+
+        Option::from(Self::from_be_bytes_padded(bytes.as_bytes(), bits_precision))
+            .ok_or_else(|| Tag::Integer.length_error())
+    }
+
+    /// Serialize into big endian bytes of the full precision, for [`UintRef`] to strip.
+    fn der_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; self.as_limbs().len() * Limb::BYTES];
+        let _ = self.to_be_bytes_padded(&mut bytes);
+        bytes
+    }
+}
+
+impl<'a> TryFrom<AnyRef<'a>> for BoxedUint {
+    type Error = der::Error;
+
+    fn try_from(any: AnyRef<'a>) -> der::Result<BoxedUint> {
+        UintRef::try_from(any)?.try_into()
+    }
+}
+
+impl<'a> TryFrom<UintRef<'a>> for BoxedUint {
+    type Error = der::Error;
+
+    fn try_from(bytes: UintRef<'a>) -> der::Result<BoxedUint> {
+        let bits_precision = Self::der_precision(bytes.as_bytes().len());
+        Self::from_uint_ref(bytes, bits_precision)
+    }
+}
+
+impl<'a> DecodeValue<'a> for BoxedUint {
+    fn decode_value<R: der::Reader<'a>>(reader: &mut R, header: der::Header) -> der::Result<Self> {
+        UintRef::decode_value(reader, header)?.try_into()
+    }
+}
+
+impl EncodeValue for BoxedUint {
+    fn value_len(&self) -> der::Result<Length> {
+        UintRef::new(&self.der_bytes())?.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut impl der::Writer) -> der::Result<()> {
+        UintRef::new(&self.der_bytes())?.encode_value(encoder)
+    }
+}
+
+impl FixedTag for BoxedUint {
+    const TAG: Tag = Tag::Integer;
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{BoxedUint, Limb};
+    use der::{Decode, Encode};
+    use hex_literal::hex;
+
+    #[test]
+    fn round_trip() {
+        let mut buf = [0u8; 64];
+        for words in [
+            &[0, 0][..],
+            &[1, 0],
+            &[0x80, 0],
+            &[Limb::MAX.0, Limb::MAX.0],
+        ] {
+            let n = BoxedUint::from_words(words);
+            let encoded = n.encode_to_slice(&mut buf).unwrap();
+            assert_eq!(BoxedUint::from_der(encoded).unwrap(), n);
+            assert_eq!(
+                BoxedUint::from_der_with_precision(encoded, Limb::BITS * 2).unwrap(),
+                n
+            );
+        }
+
+        let n = BoxedUint::from_words(&[0x80]);
+        assert_eq!(n.encode_to_slice(&mut buf).unwrap(), &hex!("02020080"));
+    }
+
+    #[test]
+    fn precision_from_length() {
+        let n = BoxedUint::from_der(&hex!("020100")).unwrap();
+        assert_eq!(n.as_limbs().len(), 1);
+
+        let n = BoxedUint::from_der(&hex!("0209 00ff00000000000000")).unwrap();
+        assert_eq!(n.as_limbs().len() * Limb::BITS, 64);
+        assert_eq!(n.bits(), 64);
+    }
+
+    #[test]
+    fn too_big() {
+        let encoded = hex!("0209 010000000000000000");
+        assert!(BoxedUint::from_der_with_precision(&encoded, 64).is_err());
+        assert!(BoxedUint::from_der_with_precision(&encoded, 128).is_ok());
+        assert!(BoxedUint::from_der_with_precision(&encoded, 65).is_err());
+    }
+}
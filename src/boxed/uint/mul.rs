@@ -0,0 +1,149 @@
+//! [`BoxedUint`] multiplication operations.
+
+use crate::{limb_slice, BoxedUint, CheckedMul, CtChoice, Limb, Zero};
+use alloc::vec;
+use subtle::{Choice, CtOption};
+
+impl BoxedUint {
+    /// Multiply `self` by `rhs`, returning a "wide" result whose precision is the sum of the
+    /// precisions of the operands.
+    pub fn mul_wide(&self, rhs: &Self) -> Self {
+        let mut limbs = vec![Limb::ZERO; self.limbs.len() + rhs.limbs.len()];
+        limb_slice::mul_basecase(&mut limbs, &self.limbs, &rhs.limbs);
+        Self { limbs }
+    }
+
+    /// Perform wrapping multiplication, discarding overflow. The result has the precision
+    /// of `self`.
+    pub fn wrapping_mul(&self, rhs: &Self) -> Self {
+        let mut res = self.mul_wide(rhs);
+        res.limbs.truncate(self.limbs.len());
+        res
+    }
+
+    /// Computes `self * rhs`, returning the product truncated to the precision of `self`
+    /// along with [`CtChoice::TRUE`] if any of the discarded bits were set.
+    pub fn overflowing_mul(&self, rhs: &Self) -> (Self, CtChoice) {
+        let mut res = self.mul_wide(rhs);
+        let hi = Self {
+            limbs: res.limbs.split_off(self.limbs.len()),
+        };
+        (res, hi.ct_is_nonzero())
+    }
+
+    /// Computes `self * rhs + carry + addend`, returning the "wide" result as `(lo, hi)`,
+    /// where `lo` has the precision of `self` and `hi` the precision of `rhs`.
+    ///
+    /// This never overflows, since `carry` and `addend` are no wider than either operand.
+    ///
+    /// # Panics
+    ///
+    /// If `carry` or `addend` is wider than `self` or `rhs`.
+    pub fn carrying_mul_add(&self, rhs: &Self, carry: &Self, addend: &Self) -> (Self, Self) {
+        let nlimbs = self.limbs.len().min(rhs.limbs.len());
+        assert!(
+            carry.limbs.len() <= nlimbs && addend.limbs.len() <= nlimbs,
+            "`carry` and `addend` must not be wider than the operands"
+        );
+
+        let mut res = self.mul_wide(rhs);
+        limb_slice::add_n(&mut res.limbs, &carry.limbs, Limb::ZERO);
+        limb_slice::add_n(&mut res.limbs, &addend.limbs, Limb::ZERO);
+        let hi = Self {
+            limbs: res.limbs.split_off(self.limbs.len()),
+        };
+        (res, hi)
+    }
+
+    /// Square `self`, returning a "wide" result with twice the precision.
+    pub fn square_wide(&self) -> Self {
+        let mut limbs = vec![Limb::ZERO; 2 * self.limbs.len()];
+        limb_slice::sqr_basecase(&mut limbs, &self.limbs);
+        Self { limbs }
+    }
+}
+
+impl CheckedMul<&BoxedUint> for BoxedUint {
+    type Output = Self;
+
+    /// Multiply `self` by `rhs`, returning `None` if the product doesn't fit in the precision
+    /// of `self`.
+    fn checked_mul(&self, rhs: &Self) -> CtOption<Self> {
+        let mut res = self.mul_wide(rhs);
+        let no_overflow = res.limbs[self.limbs.len()..]
+            .iter()
+            .fold(Choice::from(1), |acc, limb| acc & limb.is_zero());
+        res.limbs.truncate(self.limbs.len());
+        CtOption::new(res, no_overflow)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{BoxedUint, CheckedMul, Limb, Word};
+
+    #[test]
+    fn mul_wide_max() {
+        let max = BoxedUint::max(Limb::BITS).unwrap();
+        let res = max.mul_wide(&BoxedUint::max(2 * Limb::BITS).unwrap());
+        assert_eq!(res.bits(), 3 * Limb::BITS);
+        assert_eq!(res.as_words(), &[1, Word::MAX, Word::MAX - 1]);
+    }
+
+    #[test]
+    fn wrapping_mul() {
+        let max = BoxedUint::max(Limb::BITS).unwrap();
+        assert_eq!(max.wrapping_mul(&max), BoxedUint::one());
+    }
+
+    #[test]
+    fn checked_mul() {
+        let max = BoxedUint::max(Limb::BITS).unwrap();
+        let wide = BoxedUint::from_words(&[Word::MAX, 0]);
+        assert!(bool::from(max.checked_mul(&max).is_none()));
+        assert_eq!(
+            wide.checked_mul(&max).unwrap(),
+            BoxedUint::from_words(&[1, Word::MAX - 1])
+        );
+    }
+
+    #[test]
+    fn square_wide() {
+        let max = BoxedUint::max(2 * Limb::BITS).unwrap();
+        assert_eq!(max.square_wide(), max.mul_wide(&max));
+        assert_eq!(BoxedUint::zero().square_wide(), BoxedUint::zero());
+    }
+
+    #[test]
+    fn overflowing_mul() {
+        let x = BoxedUint::from_words(&[3, 0]);
+        let (res, overflow) = x.overflowing_mul(&BoxedUint::from_words(&[5]));
+        assert_eq!(res, BoxedUint::from_words(&[15, 0]));
+        assert!(!bool::from(overflow));
+
+        let max = BoxedUint::max(2 * Limb::BITS).unwrap();
+        let (res, overflow) = max.overflowing_mul(&BoxedUint::from_words(&[2]));
+        assert_eq!(res, BoxedUint::from_words(&[Word::MAX - 1, Word::MAX]));
+        assert!(bool::from(overflow));
+    }
+
+    #[test]
+    fn carrying_mul_add() {
+        let max = BoxedUint::max(2 * Limb::BITS).unwrap();
+        let (lo, hi) = max.carrying_mul_add(&max, &max, &max);
+        assert_eq!((lo, hi), (max.clone(), max));
+
+        let x = BoxedUint::from_words(&[3]);
+        let (lo, hi) = x.carrying_mul_add(&BoxedUint::from_words(&[5]), &x, &BoxedUint::one());
+        assert_eq!(lo, BoxedUint::from_words(&[19]));
+        assert_eq!(hi, BoxedUint::from_words(&[0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn carrying_mul_add_wide_addend() {
+        let max = BoxedUint::max(2 * Limb::BITS).unwrap();
+        max.carrying_mul_add(&BoxedUint::from_words(&[Word::MAX]), &max, &max);
+    }
+}
@@ -0,0 +1,56 @@
+//! [`BoxedUint`] negation operations.
+
+use crate::{BoxedUint, CtChoice, Limb, WideWord, Word};
+
+impl BoxedUint {
+    /// Perform wrapping negation.
+    pub fn wrapping_neg(&self) -> Self {
+        let mut carry = 1;
+        let limbs = self
+            .limbs
+            .iter()
+            .map(|limb| {
+                let r = (!limb.0 as WideWord) + carry;
+                carry = r >> Limb::BITS;
+                Limb(r as Word)
+            })
+            .collect();
+        Self { limbs }
+    }
+
+    /// Computes `-self`, returning the result along with [`CtChoice::TRUE`]
+    /// if `self` is non-zero (i.e. the negation wrapped around).
+    pub fn overflowing_neg(&self) -> (Self, CtChoice) {
+        (self.wrapping_neg(), self.ct_is_nonzero())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{BoxedUint, Limb, Word};
+
+    #[test]
+    fn wrapping_neg() {
+        assert_eq!(BoxedUint::zero().wrapping_neg(), BoxedUint::zero());
+        assert_eq!(
+            BoxedUint::one().wrapping_neg(),
+            BoxedUint::max(Limb::BITS).unwrap()
+        );
+        assert_eq!(
+            BoxedUint::from_words(&[0, 1]).wrapping_neg(),
+            BoxedUint::from_words(&[0, Word::MAX])
+        );
+    }
+
+    #[test]
+    fn overflowing_neg() {
+        let (res, overflow) = BoxedUint::new(128).unwrap().overflowing_neg();
+        assert_eq!(res, BoxedUint::new(128).unwrap());
+        assert!(!bool::from(overflow));
+
+        let (res, overflow) = BoxedUint::from_words(&[1, 0]).overflowing_neg();
+        assert_eq!(res, BoxedUint::max(128).unwrap());
+        assert!(bool::from(overflow));
+    }
+}
@@ -0,0 +1,203 @@
+//! Serde support for [`BoxedUint`].
+//!
+//! A [`BoxedUint`] is serialized as the little endian bytes of its full precision: lower case
+//! hex in human-readable formats, and a byte string in binary formats. The precision is
+//! recovered from the length of the encoding, which must be a non-zero multiple of the
+//! [`Limb`][`crate::Limb`] size.
+//!
+//! Deserializing a [`BoxedUint`] accepts inputs of any length, so this module also provides
+//! helpers for use with `#[serde(with = "...")]` which bound the precision:
+//!
+//! ```
+//! use crypto_bigint::{boxed_serde::FixedPrecision, BoxedUint};
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct PublicKey {
+//!     #[serde(with = "FixedPrecision::<2048>")]
+//!     n: BoxedUint,
+//! }
+//! ```
+
+use super::BoxedUint;
+use alloc::vec;
+use serdect::serde::{de::Error as _, ser::Error as _};
+use serdect::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl<'de> Deserialize<'de> for BoxedUint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = serdect::slice::deserialize_hex_or_bin_vec(deserializer)?;
+        BoxedUint::from_le_byte_slice(&bytes, bytes.len() * 8).ok_or_else(|| {
+            D::Error::invalid_length(bytes.len(), &"a non-zero multiple of the limb size")
+        })
+    }
+}
+
+impl Serialize for BoxedUint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serdect::slice::serialize_hex_lower_or_bin(&self.to_le_byte_vec(), serializer)
+    }
+}
+
+/// Serializes a [`BoxedUint`] with exactly `BITS` bits of precision, and deserializes
+/// it into a [`BoxedUint`] with exactly `BITS` bits of precision.
+///
+/// Inputs shorter than `BITS` are zero-extended, longer ones are rejected, as are values
+/// which don't fit when serializing. `BITS` must be a multiple of the [`Limb`][`crate::Limb`] size.
+#[derive(Clone, Copy, Debug)]
+pub struct FixedPrecision<const BITS: usize>;
+
+impl<const BITS: usize> FixedPrecision<BITS> {
+    /// Serialize `value` padded or truncated to `BITS` bits of precision.
+    ///
+    /// Returns an error if the value doesn't fit. The check is constant-time.
+    pub fn serialize<S>(value: &BoxedUint, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if BoxedUint::new(BITS).is_none() {
+            return Err(S::Error::custom("invalid precision"));
+        }
+
+        let mut bytes = value.to_le_byte_vec();
+        let truncated = bytes.iter().skip(BITS / 8).fold(0, |acc, byte| acc | byte);
+        if truncated != 0 {
+            return Err(S::Error::custom("value exceeds the precision"));
+        }
+
+        bytes.resize(BITS / 8, 0);
+        serdect::slice::serialize_hex_lower_or_bin(&bytes, serializer)
+    }
+
+    /// Deserialize a [`BoxedUint`] with `BITS` bits of precision.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<BoxedUint, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut buffer = vec![0u8; BITS / 8];
+        let bytes = serdect::slice::deserialize_hex_or_bin(&mut buffer, deserializer)?;
+        BoxedUint::from_le_byte_slice(bytes, BITS)
+            .ok_or_else(|| D::Error::custom("invalid precision"))
+    }
+}
+
+/// Serializes and deserializes a [`BoxedUint`] like its own [`Serialize`] and [`Deserialize`]
+/// impls, but rejects values with more than `BITS` bits of precision.
+#[derive(Clone, Copy, Debug)]
+pub struct MaxPrecision<const BITS: usize>;
+
+impl<const BITS: usize> MaxPrecision<BITS> {
+    /// Serialize `value`, returning an error if its precision exceeds `BITS`.
+    pub fn serialize<S>(value: &BoxedUint, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if value.bits() > BITS {
+            return Err(S::Error::custom("precision exceeds the bound"));
+        }
+
+        value.serialize(serializer)
+    }
+
+    /// Deserialize a [`BoxedUint`], returning an error if its precision exceeds `BITS`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<BoxedUint, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut buffer = vec![0u8; BITS / 8];
+        let bytes = serdect::slice::deserialize_hex_or_bin(&mut buffer, deserializer)?;
+        BoxedUint::from_le_byte_slice(bytes, bytes.len() * 8).ok_or_else(|| {
+            D::Error::invalid_length(bytes.len(), &"a non-zero multiple of the limb size")
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{FixedPrecision, MaxPrecision};
+    use crate::{BoxedUint, Limb};
+    use serdect::serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(crate = "serdect::serde")]
+    struct Fixed {
+        #[serde(with = "FixedPrecision::<128>")]
+        n: BoxedUint,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(crate = "serdect::serde")]
+    struct Bounded {
+        #[serde(with = "MaxPrecision::<128>")]
+        n: BoxedUint,
+    }
+
+    fn boxed(words: &[crate::Word]) -> BoxedUint {
+        BoxedUint::from_words(words)
+    }
+
+    #[test]
+    fn round_trip() {
+        for n in [boxed(&[0]), boxed(&[1, 2]), boxed(&[Limb::MAX.0; 3])] {
+            let encoded = bincode::serialize(&n).unwrap();
+            assert_eq!(bincode::deserialize::<BoxedUint>(&encoded).unwrap(), n);
+
+            let encoded = serde_json::to_string(&n).unwrap();
+            let decoded: BoxedUint = serde_json::from_str(&encoded).unwrap();
+            assert_eq!(decoded.bits(), n.bits());
+            assert_eq!(decoded, n);
+        }
+    }
+
+    #[test]
+    fn canonical_form() {
+        let n = boxed(&[0x0102]);
+        let expected = format!("\"0201{}\"", "00".repeat(Limb::BYTES - 2));
+        assert_eq!(serde_json::to_string(&n).unwrap(), expected);
+    }
+
+    #[test]
+    fn reject_invalid_length() {
+        assert!(serde_json::from_str::<BoxedUint>("\"\"").is_err());
+        assert!(serde_json::from_str::<BoxedUint>("\"010203\"").is_err());
+    }
+
+    #[test]
+    fn fixed_precision() {
+        let value = Fixed { n: boxed(&[5]) };
+        let encoded = serde_json::to_string(&value).unwrap();
+        let decoded: Fixed = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(decoded.n.bits(), 128);
+        assert_eq!(decoded.n.as_words()[0], 5);
+
+        // Shorter inputs are zero-extended
+        let decoded: Fixed = serde_json::from_str("{\"n\":\"07\"}").unwrap();
+        assert_eq!(decoded.n.bits(), 128);
+        assert_eq!(decoded.n.as_words()[0], 7);
+
+        // Longer inputs and values are rejected
+        let long = format!("{{\"n\":\"{}\"}}", "00".repeat(17));
+        assert!(serde_json::from_str::<Fixed>(&long).is_err());
+        let mut words = [0; 256 / Limb::BITS];
+        words[words.len() - 1] = 1;
+        assert!(serde_json::to_string(&Fixed { n: boxed(&words) }).is_err());
+    }
+
+    #[test]
+    fn max_precision() {
+        let value = Bounded { n: boxed(&[5]) };
+        let encoded = bincode::serialize(&value).unwrap();
+        assert_eq!(bincode::deserialize::<Bounded>(&encoded).unwrap(), value);
+
+        let too_big = BoxedUint::new(256).unwrap();
+        assert!(bincode::serialize(&Bounded { n: too_big.clone() }).is_err());
+        let encoded = bincode::serialize(&too_big).unwrap();
+        assert!(bincode::deserialize::<Bounded>(&encoded).is_err());
+    }
+}
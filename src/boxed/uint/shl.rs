@@ -0,0 +1,210 @@
+//! [`BoxedUint`] bitwise left shift operations.
+
+use crate::{BoxedUint, CtChoice, Limb, UintLike, Word};
+use core::ops::{Shl, ShlAssign};
+use subtle::CtOption;
+
+impl BoxedUint {
+    /// Computes `self << shift`.
+    /// Returns zero if `shift >= self.bits()`.
+    ///
+    /// This is constant-time with respect to both `self` and `shift`.
+    pub fn shl(&self, shift: usize) -> Self {
+        let (result, overflow) = self.overflowing_shl(shift);
+        Self::ct_select(&result, &self.zero_like(), overflow)
+    }
+
+    /// Computes `self << shift`, returning a [`CtOption`] which `is_some`
+    /// only if `shift < self.bits()`.
+    pub fn checked_shl(&self, shift: usize) -> CtOption<Self> {
+        let (result, overflow) = self.overflowing_shl(shift);
+        CtOption::new(result, overflow.not().into())
+    }
+
+    /// Computes `self` rotated left by `shift % self.bits()` bits.
+    ///
+    /// This is constant-time with respect to both `self` and `shift`.
+    pub fn rotl(&self, shift: usize) -> Self {
+        let bits = self.bits();
+        if bits == 0 {
+            return self.clone();
+        }
+
+        let shift = self.reduce_shift(shift);
+        let shifted = self.shl(shift);
+        let wrapped = self.shr(bits - shift);
+        let limbs = shifted
+            .limbs
+            .iter()
+            .zip(wrapped.limbs.iter())
+            .map(|(a, b)| a.bitor(*b))
+            .collect();
+        Self { limbs }
+    }
+
+    /// Computes `self` rotated left by `shift % self.bits()` bits.
+    ///
+    /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
+    ///
+    /// When used with a fixed `shift`, this function is constant-time with respect
+    /// to `self`.
+    pub fn rotl_vartime(&self, shift: usize) -> Self {
+        let bits = self.bits();
+        if bits == 0 {
+            return self.clone();
+        }
+
+        let shift = shift % bits;
+        let shifted = self.shl_vartime(shift);
+        let wrapped = self.shr_vartime(bits - shift);
+        let limbs = shifted
+            .limbs
+            .iter()
+            .zip(wrapped.limbs.iter())
+            .map(|(a, b)| a.bitor(*b))
+            .collect();
+        Self { limbs }
+    }
+
+    /// Computes `self << (shift % self.bits())`, returning the result along with
+    /// [`CtChoice::TRUE`] if `shift >= self.bits()`.
+    ///
+    /// This is constant-time with respect to both `self` and `shift`.
+    pub fn overflowing_shl(&self, shift: usize) -> (Self, CtChoice) {
+        let bits = self.bits();
+        let overflow = CtChoice::from_usize_lt(shift, bits).not();
+        if bits == 0 {
+            return (self.clone(), overflow);
+        }
+
+        let shift = self.reduce_shift(shift);
+        let log2_bits = (usize::BITS - (bits - 1).leading_zeros()) as usize;
+        let mut result = self.clone();
+        for i in 0..log2_bits {
+            let bit = CtChoice::from_lsb(((shift >> i) & 1) as Word);
+            result = Self::ct_select(&result, &result.shl_vartime(1 << i), bit);
+        }
+
+        (result, overflow)
+    }
+
+    /// Computes `self << shift`, discarding the bits shifted out.
+    ///
+    /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
+    ///
+    /// When used with a fixed `shift`, this function is constant-time with respect
+    /// to `self`.
+    pub fn shl_vartime(&self, shift: usize) -> Self {
+        let nlimbs = self.limbs.len();
+        let full_shifts = shift / Limb::BITS;
+        let small_shift = shift % Limb::BITS;
+        let mut res = Self {
+            limbs: vec![Limb::ZERO; nlimbs],
+        };
+
+        for i in full_shifts..nlimbs {
+            let mut limb = self.limbs[i - full_shifts].0 << small_shift;
+            if small_shift > 0 && i > full_shifts {
+                limb |= self.limbs[i - full_shifts - 1].0 >> (Limb::BITS - small_shift);
+            }
+            res.limbs[i] = Limb(limb);
+        }
+
+        res
+    }
+}
+
+impl Shl<usize> for BoxedUint {
+    type Output = BoxedUint;
+
+    fn shl(self, rhs: usize) -> BoxedUint {
+        BoxedUint::shl(&self, rhs)
+    }
+}
+
+impl Shl<usize> for &BoxedUint {
+    type Output = BoxedUint;
+
+    fn shl(self, rhs: usize) -> BoxedUint {
+        BoxedUint::shl(self, rhs)
+    }
+}
+
+impl ShlAssign<usize> for BoxedUint {
+    fn shl_assign(&mut self, rhs: usize) {
+        *self = BoxedUint::shl(self, rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedUint, Limb};
+
+    #[test]
+    fn shl_vartime() {
+        let n = BoxedUint::from_words(&[1 << (Limb::BITS - 1), 1]);
+        assert_eq!(n.shl_vartime(0), n);
+        assert_eq!(n.shl_vartime(1), BoxedUint::from_words(&[0, 3]));
+        assert_eq!(
+            n.shl_vartime(Limb::BITS),
+            BoxedUint::from_words(&[0, 1 << (Limb::BITS - 1)])
+        );
+        assert_eq!(
+            n.shl_vartime(2 * Limb::BITS),
+            BoxedUint::from_words(&[0, 0])
+        );
+    }
+
+    #[test]
+    fn overflowing_shl() {
+        let n = BoxedUint::from_words(&[1 << (Limb::BITS - 1), 1]);
+        for shift in 0..n.bits() {
+            let (res, overflow) = n.overflowing_shl(shift);
+            assert_eq!(res, n.shl_vartime(shift));
+            assert!(!bool::from(overflow));
+        }
+
+        let (res, overflow) = n.overflowing_shl(n.bits() + 1);
+        assert_eq!(res, n.shl_vartime(1));
+        assert!(bool::from(overflow));
+
+        let (res, overflow) = n.overflowing_shl(usize::MAX);
+        assert_eq!(res, n.shl_vartime(usize::MAX % n.bits()));
+        assert!(bool::from(overflow));
+
+        let (res, overflow) = BoxedUint::zero().overflowing_shl(1);
+        assert_eq!(res, BoxedUint::zero());
+        assert!(bool::from(overflow));
+    }
+
+    #[test]
+    fn shl() {
+        let n = BoxedUint::from_words(&[1 << (Limb::BITS - 1), 1]);
+        for shift in 0..=n.bits() + 1 {
+            assert_eq!(n.shl(shift), n.shl_vartime(shift));
+            assert_eq!(&n << shift, n.shl_vartime(shift));
+            assert_eq!(bool::from(n.checked_shl(shift).is_some()), shift < n.bits());
+        }
+
+        let mut x = n.clone();
+        x <<= 1;
+        assert_eq!(x, n.shl_vartime(1));
+        assert_eq!(n.clone() << n.bits(), BoxedUint::from_words(&[0, 0]));
+    }
+
+    #[test]
+    fn rotl() {
+        let n = BoxedUint::from_words(&[0x0123_4567, 0x89ab_cdef]);
+        for shift in 0..=2 * n.bits() {
+            let res = n.rotl(shift);
+            assert_eq!(res, n.rotl_vartime(shift));
+            assert_eq!(res.rotr(shift), n);
+        }
+        assert_eq!(n.rotl(n.bits()), n);
+        assert_eq!(n.rotl(usize::MAX), n.rotl_vartime(usize::MAX));
+        assert_eq!(
+            n.rotl(Limb::BITS),
+            BoxedUint::from_words(&[0x89ab_cdef, 0x0123_4567])
+        );
+    }
+}
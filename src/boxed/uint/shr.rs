@@ -0,0 +1,210 @@
+//! [`BoxedUint`] bitwise right shift operations.
+
+use crate::{BoxedUint, CtChoice, Limb, UintLike, Word};
+use core::ops::{Shr, ShrAssign};
+use subtle::CtOption;
+
+impl BoxedUint {
+    /// Computes `self >> shift`.
+    /// Returns zero if `shift >= self.bits()`.
+    ///
+    /// This is constant-time with respect to both `self` and `shift`.
+    pub fn shr(&self, shift: usize) -> Self {
+        let (result, overflow) = self.overflowing_shr(shift);
+        Self::ct_select(&result, &self.zero_like(), overflow)
+    }
+
+    /// Computes `self >> shift`, returning a [`CtOption`] which `is_some`
+    /// only if `shift < self.bits()`.
+    pub fn checked_shr(&self, shift: usize) -> CtOption<Self> {
+        let (result, overflow) = self.overflowing_shr(shift);
+        CtOption::new(result, overflow.not().into())
+    }
+
+    /// Computes `self` rotated right by `shift % self.bits()` bits.
+    ///
+    /// This is constant-time with respect to both `self` and `shift`.
+    pub fn rotr(&self, shift: usize) -> Self {
+        let bits = self.bits();
+        if bits == 0 {
+            return self.clone();
+        }
+
+        let shift = self.reduce_shift(shift);
+        let shifted = self.shr(shift);
+        let wrapped = self.shl(bits - shift);
+        let limbs = shifted
+            .limbs
+            .iter()
+            .zip(wrapped.limbs.iter())
+            .map(|(a, b)| a.bitor(*b))
+            .collect();
+        Self { limbs }
+    }
+
+    /// Computes `self` rotated right by `shift % self.bits()` bits.
+    ///
+    /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
+    ///
+    /// When used with a fixed `shift`, this function is constant-time with respect
+    /// to `self`.
+    pub fn rotr_vartime(&self, shift: usize) -> Self {
+        let bits = self.bits();
+        if bits == 0 {
+            return self.clone();
+        }
+
+        let shift = shift % bits;
+        let shifted = self.shr_vartime(shift);
+        let wrapped = self.shl_vartime(bits - shift);
+        let limbs = shifted
+            .limbs
+            .iter()
+            .zip(wrapped.limbs.iter())
+            .map(|(a, b)| a.bitor(*b))
+            .collect();
+        Self { limbs }
+    }
+
+    /// Computes `self >> (shift % self.bits())`, returning the result along with
+    /// [`CtChoice::TRUE`] if `shift >= self.bits()`.
+    ///
+    /// This is constant-time with respect to both `self` and `shift`.
+    pub fn overflowing_shr(&self, shift: usize) -> (Self, CtChoice) {
+        let bits = self.bits();
+        let overflow = CtChoice::from_usize_lt(shift, bits).not();
+        if bits == 0 {
+            return (self.clone(), overflow);
+        }
+
+        let shift = self.reduce_shift(shift);
+        let log2_bits = (usize::BITS - (bits - 1).leading_zeros()) as usize;
+        let mut result = self.clone();
+        for i in 0..log2_bits {
+            let bit = CtChoice::from_lsb(((shift >> i) & 1) as Word);
+            result = Self::ct_select(&result, &result.shr_vartime(1 << i), bit);
+        }
+
+        (result, overflow)
+    }
+
+    /// Computes `self >> shift`.
+    ///
+    /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
+    ///
+    /// When used with a fixed `shift`, this function is constant-time with respect
+    /// to `self`.
+    pub fn shr_vartime(&self, shift: usize) -> Self {
+        let nlimbs = self.limbs.len();
+        let full_shifts = shift / Limb::BITS;
+        let small_shift = shift % Limb::BITS;
+        let mut res = Self {
+            limbs: vec![Limb::ZERO; nlimbs],
+        };
+
+        for i in 0..nlimbs.saturating_sub(full_shifts) {
+            let mut limb = self.limbs[i + full_shifts].0 >> small_shift;
+            if small_shift > 0 && i + full_shifts + 1 < nlimbs {
+                limb |= self.limbs[i + full_shifts + 1].0 << (Limb::BITS - small_shift);
+            }
+            res.limbs[i] = Limb(limb);
+        }
+
+        res
+    }
+}
+
+impl Shr<usize> for BoxedUint {
+    type Output = BoxedUint;
+
+    fn shr(self, rhs: usize) -> BoxedUint {
+        BoxedUint::shr(&self, rhs)
+    }
+}
+
+impl Shr<usize> for &BoxedUint {
+    type Output = BoxedUint;
+
+    fn shr(self, rhs: usize) -> BoxedUint {
+        BoxedUint::shr(self, rhs)
+    }
+}
+
+impl ShrAssign<usize> for BoxedUint {
+    fn shr_assign(&mut self, rhs: usize) {
+        *self = BoxedUint::shr(self, rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedUint, Limb};
+
+    #[test]
+    fn shr_vartime() {
+        let n = BoxedUint::from_words(&[1, 3]);
+        assert_eq!(n.shr_vartime(0), n);
+        assert_eq!(
+            n.shr_vartime(1),
+            BoxedUint::from_words(&[1 << (Limb::BITS - 1), 1])
+        );
+        assert_eq!(n.shr_vartime(Limb::BITS), BoxedUint::from_words(&[3, 0]));
+        assert_eq!(
+            n.shr_vartime(2 * Limb::BITS),
+            BoxedUint::from_words(&[0, 0])
+        );
+    }
+
+    #[test]
+    fn overflowing_shr() {
+        let n = BoxedUint::from_words(&[1 << (Limb::BITS - 1), 1]);
+        for shift in 0..n.bits() {
+            let (res, overflow) = n.overflowing_shr(shift);
+            assert_eq!(res, n.shr_vartime(shift));
+            assert!(!bool::from(overflow));
+        }
+
+        let (res, overflow) = n.overflowing_shr(n.bits() + 1);
+        assert_eq!(res, n.shr_vartime(1));
+        assert!(bool::from(overflow));
+
+        let (res, overflow) = n.overflowing_shr(usize::MAX);
+        assert_eq!(res, n.shr_vartime(usize::MAX % n.bits()));
+        assert!(bool::from(overflow));
+
+        let (res, overflow) = BoxedUint::zero().overflowing_shr(1);
+        assert_eq!(res, BoxedUint::zero());
+        assert!(bool::from(overflow));
+    }
+
+    #[test]
+    fn shr() {
+        let n = BoxedUint::from_words(&[1 << (Limb::BITS - 1), 1]);
+        for shift in 0..=n.bits() + 1 {
+            assert_eq!(n.shr(shift), n.shr_vartime(shift));
+            assert_eq!(&n >> shift, n.shr_vartime(shift));
+            assert_eq!(bool::from(n.checked_shr(shift).is_some()), shift < n.bits());
+        }
+
+        let mut x = n.clone();
+        x >>= 1;
+        assert_eq!(x, n.shr_vartime(1));
+        assert_eq!(n.clone() >> n.bits(), BoxedUint::from_words(&[0, 0]));
+    }
+
+    #[test]
+    fn rotr() {
+        let n = BoxedUint::from_words(&[0x0123_4567, 0x89ab_cdef]);
+        for shift in 0..=2 * n.bits() {
+            let res = n.rotr(shift);
+            assert_eq!(res, n.rotr_vartime(shift));
+            assert_eq!(res.rotl(shift), n);
+        }
+        assert_eq!(n.rotr(n.bits()), n);
+        assert_eq!(n.rotr(usize::MAX), n.rotr_vartime(usize::MAX));
+        assert_eq!(
+            n.rotr(Limb::BITS),
+            BoxedUint::from_words(&[0x89ab_cdef, 0x0123_4567])
+        );
+    }
+}
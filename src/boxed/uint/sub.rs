@@ -0,0 +1,121 @@
+//! [`BoxedUint`] subtraction operations.
+
+use crate::{limb_slice, BoxedUint, CheckedSub, CtChoice, Limb, Zero};
+use core::ops::Sub;
+use subtle::CtOption;
+
+impl BoxedUint {
+    /// Computes `a - (b + borrow)`, returning the result along with the new borrow.
+    ///
+    /// The result has the precision of the wider of the two operands.
+    #[inline(always)]
+    pub fn sbb(&self, rhs: &Self, borrow: Limb) -> (Self, Limb) {
+        let mut res = self.clone();
+        if res.limbs.len() < rhs.limbs.len() {
+            res.limbs.resize(rhs.limbs.len(), Limb::ZERO);
+        }
+
+        let borrow = limb_slice::sub_n(&mut res.limbs, &rhs.limbs, borrow);
+        (res, borrow)
+    }
+
+    /// Perform wrapping subtraction, discarding underflow.
+    pub fn wrapping_sub(&self, rhs: &Self) -> Self {
+        self.sbb(rhs, Limb::ZERO).0
+    }
+
+    /// Computes `self - rhs`, returning the result along with [`CtChoice::TRUE`]
+    /// if an underflow occurred.
+    pub fn overflowing_sub(&self, rhs: &Self) -> (Self, CtChoice) {
+        let (res, borrow) = self.sbb(rhs, Limb::ZERO);
+        (res, CtChoice::from_mask(borrow.0))
+    }
+}
+
+impl CheckedSub<&BoxedUint> for BoxedUint {
+    type Output = Self;
+
+    fn checked_sub(&self, rhs: &Self) -> CtOption<Self> {
+        let (result, borrow) = self.sbb(rhs, Limb::ZERO);
+        CtOption::new(result, borrow.is_zero())
+    }
+}
+
+impl Sub for BoxedUint {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.sub(&rhs)
+    }
+}
+
+impl Sub<&BoxedUint> for BoxedUint {
+    type Output = Self;
+
+    fn sub(self, rhs: &Self) -> Self {
+        self.checked_sub(rhs)
+            .expect("attempted to subtract with underflow")
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{BoxedUint, CheckedSub, Limb};
+
+    #[test]
+    fn sbb_no_borrow() {
+        let (res, borrow) = BoxedUint::one().sbb(&BoxedUint::one(), Limb::ZERO);
+        assert_eq!(res, BoxedUint::zero());
+        assert_eq!(borrow, Limb::ZERO);
+    }
+
+    #[test]
+    fn sbb_with_borrow() {
+        let (res, borrow) = BoxedUint::zero().sbb(&BoxedUint::one(), Limb::ZERO);
+        assert_eq!(res, BoxedUint::max(Limb::BITS).unwrap());
+        assert_eq!(borrow, Limb::MAX);
+    }
+
+    #[test]
+    fn sbb_wider_rhs() {
+        let (res, borrow) = BoxedUint::one().sbb(&BoxedUint::new(256).unwrap(), Limb::ZERO);
+        assert_eq!(res, BoxedUint::one());
+        assert_eq!(res.bits(), 256);
+        assert_eq!(borrow, Limb::ZERO);
+    }
+
+    #[test]
+    fn checked_sub_ok() {
+        let result = BoxedUint::one().checked_sub(&BoxedUint::one());
+        assert_eq!(result.unwrap(), BoxedUint::zero());
+    }
+
+    #[test]
+    fn checked_sub_overflow() {
+        let result = BoxedUint::zero().checked_sub(&BoxedUint::one());
+        assert!(!bool::from(result.is_some()));
+    }
+
+    #[test]
+    fn sub_ok() {
+        assert_eq!(BoxedUint::one() - BoxedUint::one(), BoxedUint::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to subtract with underflow")]
+    fn sub_underflow() {
+        let _ = BoxedUint::zero() - &BoxedUint::one();
+    }
+
+    #[test]
+    fn overflowing_sub() {
+        let (res, overflow) = BoxedUint::one().overflowing_sub(&BoxedUint::one());
+        assert_eq!(res, BoxedUint::zero());
+        assert!(!bool::from(overflow));
+
+        let (res, overflow) = BoxedUint::from_words(&[0]).overflowing_sub(&BoxedUint::one());
+        assert_eq!(res, BoxedUint::max(Limb::BITS).unwrap());
+        assert!(bool::from(overflow));
+    }
+}
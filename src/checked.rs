@@ -1,5 +1,6 @@
 //! Checked arithmetic.
 
+use crate::{Bounded, Uint};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "serde")]
@@ -9,6 +10,15 @@ use serdect::serde::{Deserialize, Deserializer, Serialize, Serializer};
 ///
 /// Internally this leverages the [`CtOption`] type from the [`subtle`] crate
 /// in order to handle overflows.
+///
+/// `Checked<Uint<LIMBS>>` implements [`Encoding`][`crate::Encoding`] with one more byte than
+/// [`Uint`], holding the failure flag: `1` if the value is present and `0` otherwise. It is
+/// the most significant byte, i.e. the first in big endian and the last in little endian,
+/// and absent values are encoded as zero. Any flag other than `1` decodes as absent.
+///
+/// Unlike [`Wrapping`][`crate::Wrapping`], this type does not implement
+/// [`Integer`][`crate::Integer`]: that trait requires [`Eq`] and [`Ord`], neither of which
+/// can account for the failure flag without exposing it in variable time.
 #[derive(Copy, Clone, Debug)]
 pub struct Checked<T>(pub CtOption<T>);
 
@@ -19,6 +29,19 @@ impl<T> Checked<T> {
     }
 }
 
+impl<const LIMBS: usize> Checked<Uint<LIMBS>> {
+    /// Returns the value, or zero if it is absent, along with the flag byte of the
+    /// [`Encoding`][`crate::Encoding`] representation.
+    pub(crate) fn to_flagged(self) -> (Uint<LIMBS>, u8) {
+        (self.0.unwrap_or(Uint::ZERO), self.0.is_some().unwrap_u8())
+    }
+
+    /// Inverse of [`Self::to_flagged`].
+    pub(crate) fn from_flagged(value: Uint<LIMBS>, flag: u8) -> Self {
+        Self(CtOption::new(value, flag.ct_eq(&1)))
+    }
+}
+
 impl<T> Default for Checked<T>
 where
     T: Default,
@@ -28,6 +51,11 @@ where
     }
 }
 
+impl<T: Bounded> Bounded for Checked<T> {
+    const BITS: usize = T::BITS;
+    const BYTES: usize = T::BYTES;
+}
+
 impl<T: ConditionallySelectable> ConditionallySelectable for Checked<T> {
     #[inline]
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
@@ -60,6 +88,13 @@ impl<T> From<Checked<T>> for Option<T> {
     }
 }
 
+impl<T: Default> From<Option<T>> for Checked<T> {
+    fn from(option: Option<T>) -> Checked<T> {
+        let is_some = Choice::from(option.is_some() as u8);
+        Checked(CtOption::new(option.unwrap_or_default(), is_some))
+    }
+}
+
 #[cfg(feature = "serde")]
 impl<'de, T: Default + Deserialize<'de>> Deserialize<'de> for Checked<T> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -82,13 +117,51 @@ impl<T: Copy + Serialize> Serialize for Checked<T> {
     }
 }
 
-#[cfg(all(test, feature = "serde"))]
+#[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
 
-    use crate::{Checked, U64};
-    use subtle::{Choice, ConstantTimeEq, CtOption};
+    use crate::{Checked, Encoding, U64};
+    use hex_literal::hex;
+    use subtle::{ConstantTimeEq, CtOption};
+
+    #[cfg(feature = "serde")]
+    use subtle::Choice;
+
+    #[test]
+    fn option_conversions() {
+        let some = Checked::from(Some(U64::ONE));
+        assert_eq!(Option::<U64>::from(some), Some(U64::ONE));
+        assert!(bool::from(CtOption::from(some).is_some()));
+
+        let none = Checked::<U64>::from(None);
+        assert_eq!(Option::<U64>::from(none), None);
+        assert!(bool::from(CtOption::from(none).is_none()));
+    }
+
+    #[test]
+    fn encoding() {
+        let some = Checked::new(U64::from_u64(0x0011223344556677));
+        let none = Checked::new(U64::ZERO) - Checked::new(U64::ONE);
+
+        assert_eq!(some.to_be_bytes(), hex!("010011223344556677"));
+        assert_eq!(some.to_le_bytes(), hex!("776655443322110001"));
+        assert_eq!(none.to_be_bytes(), [0; 9]);
+        assert_eq!(none.to_le_bytes(), [0; 9]);
+
+        for checked in [some, none] {
+            let decoded = Checked::<U64>::from_be_bytes(checked.to_be_bytes());
+            assert!(bool::from(checked.ct_eq(&decoded)));
+
+            let decoded = Checked::<U64>::from_le_bytes(checked.to_le_bytes());
+            assert!(bool::from(checked.ct_eq(&decoded)));
+        }
+
+        let decoded = Checked::<U64>::from_be_bytes(hex!("020011223344556677"));
+        assert!(bool::from(decoded.0.is_none()));
+    }
 
+    #[cfg(feature = "serde")]
     #[test]
     fn serde() {
         let test = Checked::new(U64::from_u64(0x0011223344556677));
@@ -109,6 +182,7 @@ mod tests {
         assert!(bool::from(test.ct_eq(&deserialized)));
     }
 
+    #[cfg(feature = "serde")]
     #[test]
     fn serde_owned() {
         let test = Checked::new(U64::from_u64(0x0011223344556677));
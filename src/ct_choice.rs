@@ -1,6 +1,6 @@
 use subtle::Choice;
 
-use crate::Word;
+use crate::{WideWord, Word};
 
 /// A boolean value returned by constant-time `const fn`s.
 // TODO: should be replaced by `subtle::Choice` or `CtOption`
@@ -46,6 +46,12 @@ impl CtChoice {
         Self::from_lsb(bit as Word)
     }
 
+    /// Returns the truthy value if `x <= y`, and the falsy value otherwise.
+    pub(crate) const fn from_wide_word_le(x: WideWord, y: WideWord) -> Self {
+        let bit = (((!x) | y) & ((x ^ y) | !(y.wrapping_sub(x)))) >> (WideWord::BITS - 1);
+        Self::from_lsb(bit as Word)
+    }
+
     pub(crate) const fn not(&self) -> Self {
         Self(!self.0)
     }
@@ -63,6 +69,12 @@ impl CtChoice {
         a ^ (self.0 & (a ^ b))
     }
 
+    /// Return `b` if `self` is truthy, otherwise return `a`.
+    pub(crate) const fn select_wide(&self, a: WideWord, b: WideWord) -> WideWord {
+        let mask = ((self.0 as WideWord) << Word::BITS) | (self.0 as WideWord);
+        a ^ (mask & (a ^ b))
+    }
+
     /// Return `x` if `self` is truthy, otherwise return 0.
     pub(crate) const fn if_true(&self, x: Word) -> Word {
         x & self.0
@@ -166,7 +166,10 @@ mod boxed;
 mod checked;
 mod ct_choice;
 mod limb;
+pub mod limb_slice;
 mod non_zero;
+#[cfg(feature = "proptest")]
+pub mod strategy;
 mod traits;
 mod uint;
 mod wrapping;
@@ -194,12 +197,24 @@ pub use subtle;
 #[cfg(feature = "alloc")]
 pub use crate::boxed::uint::BoxedUint;
 
+#[cfg(all(feature = "alloc", feature = "serde"))]
+pub use crate::boxed::uint::serde as boxed_serde;
+
+#[cfg(feature = "borsh")]
+pub use borsh;
+
+#[cfg(feature = "bytemuck")]
+pub use bytemuck;
+
 #[cfg(feature = "generic-array")]
 pub use {
     crate::array::{ArrayDecoding, ArrayEncoding, ByteArray},
     generic_array::{self, typenum::consts},
 };
 
+#[cfg(feature = "parity-scale-codec")]
+pub use parity_scale_codec;
+
 #[cfg(feature = "rand_core")]
 pub use rand_core;
 
@@ -136,6 +136,20 @@ impl fmt::UpperHex for Limb {
     }
 }
 
+impl fmt::Binary for Limb {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:0width$b}", &self.0, width = Self::BITS)
+    }
+}
+
+impl fmt::Octal for Limb {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:0width$o}", &self.0, width = (Self::BITS + 2) / 3)
+    }
+}
+
 #[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Limb {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -159,6 +173,38 @@ impl Serialize for Limb {
 #[cfg(feature = "zeroize")]
 impl zeroize::DefaultIsZeroes for Limb {}
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Limb {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        <Word as arbitrary::Arbitrary<'a>>::arbitrary(u).map(Self)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <Word as arbitrary::Arbitrary<'a>>::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for Limb {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+        proptest::arbitrary::any::<Word>().prop_map(Self).boxed()
+    }
+}
+
+// SAFETY: `Limb` is a `repr(transparent)` newtype for `Word`
+#[cfg(feature = "bytemuck")]
+#[allow(unsafe_code)]
+unsafe impl bytemuck::Zeroable for Limb {}
+
+// SAFETY: `Limb` is a `repr(transparent)` newtype for `Word`
+#[cfg(feature = "bytemuck")]
+#[allow(unsafe_code)]
+unsafe impl bytemuck::Pod for Limb {}
+
 #[cfg(test)]
 mod tests {
     #[cfg(feature = "alloc")]
@@ -1,6 +1,6 @@
 //! Limb addition
 
-use crate::{Checked, CheckedAdd, Limb, WideWord, Word, Wrapping, Zero};
+use crate::{Checked, CheckedAdd, CtChoice, Limb, WideWord, Word, Wrapping, Zero};
 use core::ops::{Add, AddAssign};
 use subtle::CtOption;
 
@@ -26,6 +26,14 @@ impl Limb {
     pub const fn wrapping_add(&self, rhs: Self) -> Self {
         Limb(self.0.wrapping_add(rhs.0))
     }
+
+    /// Computes `self + rhs`, returning the result along with [`CtChoice::TRUE`]
+    /// if an overflow occurred.
+    #[inline(always)]
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, CtChoice) {
+        let (res, carry) = self.adc(rhs, Limb::ZERO);
+        (res, CtChoice::from_lsb(carry.0))
+    }
 }
 
 impl CheckedAdd for Limb {
@@ -177,4 +185,15 @@ mod tests {
         let result = Limb::MAX.checked_add(Limb::ONE);
         assert!(!bool::from(result.is_some()));
     }
+
+    #[test]
+    fn overflowing_add() {
+        let (res, overflow) = Limb::ONE.overflowing_add(Limb::ONE);
+        assert_eq!(res, Limb::from(2u8));
+        assert!(!bool::from(overflow));
+
+        let (res, overflow) = Limb::MAX.overflowing_add(Limb::from(2u8));
+        assert_eq!(res, Limb::ONE);
+        assert!(bool::from(overflow));
+    }
 }
@@ -20,4 +20,9 @@ impl Limb {
     pub const fn trailing_ones(self) -> usize {
         self.0.trailing_ones() as usize
     }
+
+    /// Calculate the number of ones in the binary representation of this number.
+    pub const fn count_ones(self) -> usize {
+        self.0.count_ones() as usize
+    }
 }
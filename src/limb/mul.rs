@@ -1,6 +1,6 @@
 //! Limb multiplication
 
-use crate::{Checked, CheckedMul, Limb, WideWord, Word, Wrapping, Zero};
+use crate::{Checked, CheckedMul, CtChoice, Limb, WideWord, Word, Wrapping, Zero};
 use core::ops::{Mul, MulAssign};
 use subtle::CtOption;
 
@@ -28,6 +28,23 @@ impl Limb {
         Limb(self.0.wrapping_mul(rhs.0))
     }
 
+    /// Computes `self * rhs`, returning the low half of the product along with
+    /// [`CtChoice::TRUE`] if the high half is non-zero.
+    #[inline(always)]
+    pub const fn overflowing_mul(self, rhs: Self) -> (Self, CtChoice) {
+        let res = self.mul_wide(rhs);
+        let hi = Limb((res >> Self::BITS) as Word);
+        (Limb(res as Word), hi.ct_is_nonzero())
+    }
+
+    /// Computes `self * rhs + carry + addend`, returning the result as `(lo, hi)`.
+    ///
+    /// The result always fits in two limbs, so this never overflows.
+    #[inline(always)]
+    pub const fn carrying_mul_add(self, rhs: Self, carry: Self, addend: Self) -> (Self, Self) {
+        addend.mac(self, rhs, carry)
+    }
+
     /// Compute "wide" multiplication, with a product twice the size of the input.
     pub(crate) const fn mul_wide(&self, rhs: Self) -> WideWord {
         (self.0 as WideWord) * (rhs.0 as WideWord)
@@ -192,4 +209,24 @@ mod tests {
         let n = Limb::MAX;
         assert!(bool::from(n.checked_mul(n).is_none()));
     }
+
+    #[test]
+    fn overflowing_mul() {
+        let (res, overflow) = Limb::from(3u8).overflowing_mul(Limb::from(5u8));
+        assert_eq!(res, Limb::from(15u8));
+        assert!(!bool::from(overflow));
+
+        let (res, overflow) = Limb::MAX.overflowing_mul(Limb::from(2u8));
+        assert_eq!(res, Limb::MAX.wrapping_sub(Limb::ONE));
+        assert!(bool::from(overflow));
+    }
+
+    #[test]
+    fn carrying_mul_add() {
+        let (lo, hi) = Limb::MAX.carrying_mul_add(Limb::MAX, Limb::MAX, Limb::MAX);
+        assert_eq!((lo, hi), (Limb::MAX, Limb::MAX));
+
+        let (lo, hi) = Limb::from(3u8).carrying_mul_add(Limb::from(5u8), Limb::ONE, Limb::ONE);
+        assert_eq!((lo, hi), (Limb::from(17u8), Limb::ZERO));
+    }
 }
@@ -1,6 +1,6 @@
 //! Limb negation
 
-use crate::{Limb, Wrapping};
+use crate::{CtChoice, Limb, Wrapping};
 use core::ops::Neg;
 
 impl Neg for Wrapping<Limb> {
@@ -17,4 +17,27 @@ impl Limb {
     pub const fn wrapping_neg(self) -> Self {
         Limb(self.0.wrapping_neg())
     }
+
+    /// Computes `-self`, returning the result along with [`CtChoice::TRUE`]
+    /// if `self` is non-zero (i.e. the negation wrapped around).
+    #[inline(always)]
+    pub const fn overflowing_neg(self) -> (Self, CtChoice) {
+        (self.wrapping_neg(), self.ct_is_nonzero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Limb;
+
+    #[test]
+    fn overflowing_neg() {
+        let (res, overflow) = Limb::ZERO.overflowing_neg();
+        assert_eq!(res, Limb::ZERO);
+        assert!(!bool::from(overflow));
+
+        let (res, overflow) = Limb::ONE.overflowing_neg();
+        assert_eq!(res, Limb::MAX);
+        assert!(bool::from(overflow));
+    }
 }
@@ -1,6 +1,6 @@
 //! Limb left bitshift
 
-use crate::{Limb, Word};
+use crate::{CtChoice, Limb, Word};
 use core::ops::{Shl, ShlAssign};
 
 impl Limb {
@@ -10,6 +10,22 @@ impl Limb {
     pub const fn shl(self, rhs: Self) -> Self {
         Limb(self.0 << rhs.0)
     }
+
+    /// Computes `self << (shift % Limb::BITS)`, returning the result along with
+    /// [`CtChoice::TRUE`] if `shift >= Limb::BITS`.
+    #[inline(always)]
+    pub const fn overflowing_shl(self, shift: usize) -> (Self, CtChoice) {
+        (
+            Limb(self.0 << (shift % Self::BITS)),
+            CtChoice::from_usize_lt(shift, Self::BITS).not(),
+        )
+    }
+
+    /// Computes `self` rotated left by `shift % Limb::BITS` bits.
+    #[inline(always)]
+    pub const fn rotl(self, shift: usize) -> Self {
+        Limb(self.0.rotate_left((shift % Self::BITS) as u32))
+    }
 }
 
 impl Shl for Limb {
@@ -71,4 +87,23 @@ mod tests {
         l <<= 2;
         assert_eq!(l, Limb(4));
     }
+
+    #[test]
+    fn overflowing_shl() {
+        let (res, overflow) = Limb::ONE.overflowing_shl(1);
+        assert_eq!(res, Limb::from(2u8));
+        assert!(!bool::from(overflow));
+
+        let (res, overflow) = Limb::ONE.overflowing_shl(Limb::BITS);
+        assert_eq!(res, Limb::ONE);
+        assert!(bool::from(overflow));
+    }
+
+    #[test]
+    fn rotl() {
+        assert_eq!(Limb::ONE.rotl(1), Limb(2));
+        assert_eq!(Limb::ONE.rotl(Limb::BITS + 1), Limb(2));
+        assert_eq!(Limb::MAX.rotl(7), Limb::MAX);
+        assert_eq!(Limb::ONE.rotl(Limb::BITS), Limb::ONE);
+    }
 }
@@ -1,6 +1,6 @@
 //! Limb right bitshift
 
-use crate::{Limb, Word};
+use crate::{CtChoice, Limb, Word};
 use core::ops::{Shr, ShrAssign};
 
 impl Limb {
@@ -10,6 +10,22 @@ impl Limb {
     pub const fn shr(self, rhs: Self) -> Self {
         Limb(self.0 >> rhs.0)
     }
+
+    /// Computes `self >> (shift % Limb::BITS)`, returning the result along with
+    /// [`CtChoice::TRUE`] if `shift >= Limb::BITS`.
+    #[inline(always)]
+    pub const fn overflowing_shr(self, shift: usize) -> (Self, CtChoice) {
+        (
+            Limb(self.0 >> (shift % Self::BITS)),
+            CtChoice::from_usize_lt(shift, Self::BITS).not(),
+        )
+    }
+
+    /// Computes `self` rotated right by `shift % Limb::BITS` bits.
+    #[inline(always)]
+    pub const fn rotr(self, shift: usize) -> Self {
+        Limb(self.0.rotate_right((shift % Self::BITS) as u32))
+    }
 }
 
 impl Shr for Limb {
@@ -71,4 +87,23 @@ mod tests {
         l >>= 2;
         assert_eq!(l, Limb(8));
     }
+
+    #[test]
+    fn overflowing_shr() {
+        let (res, overflow) = Limb::ONE.overflowing_shr(1);
+        assert_eq!(res, Limb::ZERO);
+        assert!(!bool::from(overflow));
+
+        let (res, overflow) = Limb::ONE.overflowing_shr(Limb::BITS);
+        assert_eq!(res, Limb::ONE);
+        assert!(bool::from(overflow));
+    }
+
+    #[test]
+    fn rotr() {
+        assert_eq!(Limb(2).rotr(1), Limb::ONE);
+        assert_eq!(Limb(2).rotr(Limb::BITS + 1), Limb::ONE);
+        assert_eq!(Limb::MAX.rotr(7), Limb::MAX);
+        assert_eq!(Limb(2).rotr(Limb::BITS), Limb(2));
+    }
 }
@@ -1,6 +1,6 @@
 //! Limb subtraction
 
-use crate::{Checked, CheckedSub, Limb, WideWord, Word, Wrapping, Zero};
+use crate::{Checked, CheckedSub, CtChoice, Limb, WideWord, Word, Wrapping, Zero};
 use core::ops::{Sub, SubAssign};
 use subtle::CtOption;
 
@@ -27,6 +27,14 @@ impl Limb {
     pub const fn wrapping_sub(&self, rhs: Self) -> Self {
         Limb(self.0.wrapping_sub(rhs.0))
     }
+
+    /// Computes `self - rhs`, returning the result along with [`CtChoice::TRUE`]
+    /// if an underflow occurred.
+    #[inline(always)]
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, CtChoice) {
+        let (res, borrow) = self.sbb(rhs, Limb::ZERO);
+        (res, CtChoice::from_mask(borrow.0))
+    }
 }
 
 impl CheckedSub for Limb {
@@ -179,4 +187,15 @@ mod tests {
         let result = Limb::ZERO.checked_sub(Limb::ONE);
         assert!(!bool::from(result.is_some()));
     }
+
+    #[test]
+    fn overflowing_sub() {
+        let (res, overflow) = Limb::ONE.overflowing_sub(Limb::ONE);
+        assert_eq!(res, Limb::ZERO);
+        assert!(!bool::from(overflow));
+
+        let (res, overflow) = Limb::ZERO.overflowing_sub(Limb::ONE);
+        assert_eq!(res, Limb::MAX);
+        assert!(bool::from(overflow));
+    }
 }
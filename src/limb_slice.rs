@@ -0,0 +1,451 @@
+//! Low-level arithmetic on little-endian slices of [`Limb`]s, in the spirit of GMP's `mpn`
+//! functions.
+//!
+//! These primitives back the heap-allocated `BoxedUint`, the in-place, non-`const` operations
+//! of [`Uint`][`crate::Uint`], and the Montgomery reduction of `Residue` and `DynResidue`, and
+//! can be used to implement big integers with other storage strategies.
+//!
+//! The `const fn` arithmetic of `Uint` (e.g. [`Uint::mul_wide`][`crate::Uint::mul_wide`] and
+//! [`Uint::square_wide`][`crate::Uint::square_wide`]) doesn't use them, since `const fn` can't
+//! take mutable references on the crate's MSRV. For the same reason
+//! [`montgomery_reduction`][`crate::modular::montgomery_reduction`] keeps its own `const`
+//! implementation of [`redc`], which is only used to compute constants at compile time.
+//!
+//! All functions are constant-time with respect to the values of the limbs and the shift
+//! amounts: their running time only depends on the lengths of the slices.
+//!
+//! The length requirements of each function are checked, and violating them results in a panic.
+
+use crate::{CtChoice, Limb, Word};
+use core::cmp::Ordering;
+
+/// Computes `a += b + carry`, returning the new carry.
+///
+/// `b` may be shorter than `a`, in which case it is treated as zero-extended.
+///
+/// # Panics
+///
+/// If `b` is longer than `a`.
+pub fn add_n(a: &mut [Limb], b: &[Limb], mut carry: Limb) -> Limb {
+    assert!(b.len() <= a.len(), "`b` is longer than `a`");
+
+    for (i, x) in a.iter_mut().enumerate() {
+        let y = b.get(i).copied().unwrap_or(Limb::ZERO);
+        (*x, carry) = x.adc(y, carry);
+    }
+
+    carry
+}
+
+/// Computes `a -= b + borrow`, returning the new borrow.
+///
+/// The borrow is [`Limb::MAX`] if the subtraction underflowed, and [`Limb::ZERO`] otherwise,
+/// as in [`Limb::sbb`].
+///
+/// `b` may be shorter than `a`, in which case it is treated as zero-extended.
+///
+/// # Panics
+///
+/// If `b` is longer than `a`.
+pub fn sub_n(a: &mut [Limb], b: &[Limb], mut borrow: Limb) -> Limb {
+    assert!(b.len() <= a.len(), "`b` is longer than `a`");
+
+    for (i, x) in a.iter_mut().enumerate() {
+        let y = b.get(i).copied().unwrap_or(Limb::ZERO);
+        (*x, borrow) = x.sbb(y, borrow);
+    }
+
+    borrow
+}
+
+/// Computes `a += b` if `choice` is truthy, and leaves `a` unchanged otherwise.
+/// Returns the carry.
+pub(crate) fn conditional_add_n(a: &mut [Limb], b: &[Limb], choice: CtChoice) -> Limb {
+    debug_assert!(a.len() == b.len());
+
+    let mut carry = Limb::ZERO;
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        (*x, carry) = x.adc(Limb(choice.if_true(y.0)), carry);
+    }
+
+    carry
+}
+
+/// Computes `a *= b`, returning the limb that was carried out.
+pub fn mul_1(a: &mut [Limb], b: Limb) -> Limb {
+    let mut carry = Limb::ZERO;
+
+    for x in a.iter_mut() {
+        (*x, carry) = Limb::ZERO.mac(*x, b, carry);
+    }
+
+    carry
+}
+
+/// Computes `r += a * b` over the first `a.len()` limbs of `r`,
+/// returning the limb that was carried out.
+///
+/// # Panics
+///
+/// If `r` is shorter than `a`.
+pub fn addmul_1(r: &mut [Limb], a: &[Limb], b: Limb) -> Limb {
+    assert!(r.len() >= a.len(), "`r` is shorter than `a`");
+
+    let mut carry = Limb::ZERO;
+    for (x, y) in r.iter_mut().zip(a.iter()) {
+        (*x, carry) = x.mac(*y, b, carry);
+    }
+
+    carry
+}
+
+/// Computes the full product `r = a * b` using schoolbook multiplication.
+///
+/// # Panics
+///
+/// If `r.len() != a.len() + b.len()`.
+pub fn mul_basecase(r: &mut [Limb], a: &[Limb], b: &[Limb]) {
+    assert_eq!(r.len(), a.len() + b.len(), "`r` has the wrong length");
+
+    r.fill(Limb::ZERO);
+    for (i, y) in b.iter().enumerate() {
+        r[a.len() + i] = addmul_1(&mut r[i..], a, *y);
+    }
+}
+
+/// Computes the full square `r = a * a`.
+///
+/// This only computes half of the schoolbook multiplication grid,
+/// making it faster than [`mul_basecase`].
+///
+/// # Panics
+///
+/// If `r.len() != 2 * a.len()`.
+pub fn sqr_basecase(r: &mut [Limb], a: &[Limb]) {
+    let n = a.len();
+    assert_eq!(r.len(), 2 * n, "`r` has the wrong length");
+
+    // The products of distinct limbs, above the diagonal of the multiplication grid
+    r.fill(Limb::ZERO);
+    for i in 0..n {
+        r[n + i] = addmul_1(&mut r[2 * i + 1..], &a[i + 1..], a[i]);
+    }
+
+    // Double them, accounting for the other half of the grid. The top bit is always zero.
+    shl_n(r, 1);
+
+    // Add the diagonal
+    let mut carry = Limb::ZERO;
+    for i in 0..n {
+        let (lo, hi) = Limb::ZERO.mac(a[i], a[i], Limb::ZERO);
+        (r[2 * i], carry) = r[2 * i].adc(lo, carry);
+        (r[2 * i + 1], carry) = r[2 * i + 1].adc(hi, carry);
+    }
+}
+
+/// Montgomery reduction: computes `t * R^-1 mod modulus`, where `R = 2^(Limb::BITS * n)`
+/// and `n = modulus.len()`.
+///
+/// `t` is given as its `lower` and `upper` halves (use [`slice::split_at_mut`] on a contiguous
+/// buffer). The result is written to `upper`, and the contents of `lower` are destroyed.
+///
+/// `mod_neg_inv` must be `-modulus^-1 mod 2^Limb::BITS`, and `t` must be less than
+/// `modulus * R` for the result to be fully reduced.
+///
+/// # Panics
+///
+/// If `lower.len() != n` or `upper.len() != n`.
+pub fn redc(lower: &mut [Limb], upper: &mut [Limb], modulus: &[Limb], mod_neg_inv: Limb) {
+    let n = modulus.len();
+    assert_eq!(lower.len(), n, "`lower` has the wrong length");
+    assert_eq!(upper.len(), n, "`upper` has the wrong length");
+
+    let mut meta_carry = Limb::ZERO;
+    for i in 0..n {
+        // Add `u * modulus` to the limbs `t[i..i + n]`, which straddle both halves
+        let u = lower[i].wrapping_mul(mod_neg_inv);
+        let mut carry = Limb::ZERO;
+        for (x, m) in lower[i..]
+            .iter_mut()
+            .chain(upper[..i].iter_mut())
+            .zip(modulus)
+        {
+            (*x, carry) = x.mac(u, *m, carry);
+        }
+        (upper[i], meta_carry) = upper[i].adc(carry, meta_carry);
+    }
+
+    // Division is simply taking the upper half of the limbs. At this point the value
+    // is less than `2 * modulus`, so `meta_carry` is either 0 or 1.
+    let borrow = sub_n(upper, modulus, Limb::ZERO);
+
+    // Add the modulus back iff `meta_carry == 0` and the subtraction underflowed
+    let underflow = CtChoice::from_mask((!meta_carry.0.wrapping_neg()) & borrow.0);
+    conditional_add_n(upper, modulus, underflow);
+}
+
+/// Computes `a <<= shift` where `0 <= shift < Limb::BITS`, returning the bits shifted out
+/// in the low bits of the returned limb.
+///
+/// # Panics
+///
+/// If `shift >= Limb::BITS`.
+pub fn shl_n(a: &mut [Limb], shift: usize) -> Limb {
+    assert!(shift < Limb::BITS, "`shift` is out of range");
+
+    let nz = CtChoice::from_usize_being_nonzero(shift);
+    let rshift = nz.select(0, (Limb::BITS - shift) as Word);
+
+    let mut carry = 0;
+    for x in a.iter_mut() {
+        let new_carry = nz.if_true(x.0 >> rshift);
+        *x = Limb((x.0 << shift) | carry);
+        carry = new_carry;
+    }
+
+    Limb(carry)
+}
+
+/// Computes `a >>= shift` where `0 <= shift < Limb::BITS`, returning the bits shifted out
+/// in the high bits of the returned limb.
+///
+/// # Panics
+///
+/// If `shift >= Limb::BITS`.
+pub fn shr_n(a: &mut [Limb], shift: usize) -> Limb {
+    assert!(shift < Limb::BITS, "`shift` is out of range");
+
+    let nz = CtChoice::from_usize_being_nonzero(shift);
+    let lshift = nz.select(0, (Limb::BITS - shift) as Word);
+
+    let mut carry = 0;
+    for x in a.iter_mut().rev() {
+        let new_carry = nz.if_true(x.0 << lshift);
+        *x = Limb((x.0 >> shift) | carry);
+        carry = new_carry;
+    }
+
+    Limb(carry)
+}
+
+/// Compares `a` and `b`.
+///
+/// The shorter of the two slices is treated as zero-extended.
+pub fn cmp_n(a: &[Limb], b: &[Limb]) -> Ordering {
+    let mut borrow = Limb::ZERO;
+    let mut diff = Limb::ZERO;
+
+    for i in 0..a.len().max(b.len()) {
+        let x = a.get(i).copied().unwrap_or(Limb::ZERO);
+        let y = b.get(i).copied().unwrap_or(Limb::ZERO);
+        let (w, c) = y.sbb(x, borrow);
+        diff = diff.bitor(w);
+        borrow = c;
+    }
+
+    let sgn = ((borrow.0 & 2) as i8) - 1;
+    ((diff.ct_is_nonzero().to_u8() as i8) * sgn).cmp(&0)
+}
+
+/// Returns the byte at `index` of the little-endian encoding of `a`, or zero if out of range.
+#[inline(always)]
+fn le_byte(a: &[Limb], index: usize) -> u8 {
+    match a.get(index / Limb::BYTES) {
+        Some(limb) => (limb.0 >> ((index % Limb::BYTES) * 8)) as u8,
+        None => 0,
+    }
+}
+
+/// Writes `a` as big-endian into `out`, left-padded with zeros to the length of `out`.
+///
+/// Returns a falsy [`CtChoice`] if `a` doesn't fit, in which case its most significant bytes
+/// are dropped.
+pub(crate) fn to_be_bytes_padded(a: &[Limb], out: &mut [u8]) -> CtChoice {
+    let len = out.len();
+    let mut truncated = 0u8;
+
+    for index in 0..(a.len() * Limb::BYTES).max(len) {
+        let byte = le_byte(a, index);
+        match index.checked_sub(len) {
+            None => out[len - 1 - index] = byte,
+            Some(_) => truncated |= byte,
+        }
+    }
+
+    Limb(truncated as Word).ct_is_nonzero().not()
+}
+
+/// Writes `a` as big-endian without leading zeros into the beginning of `out`,
+/// returning the number of bytes written.
+///
+/// The length of the encoding is leaked in the time pattern.
+///
+/// # Panics
+///
+/// If `out` is shorter than the encoding.
+pub(crate) fn to_be_bytes_minimal(a: &[Limb], out: &mut [u8]) -> usize {
+    let mut len = a.len() * Limb::BYTES;
+    while len > 0 && le_byte(a, len - 1) == 0 {
+        len -= 1;
+    }
+
+    assert!(out.len() >= len, "`out` is too short");
+    for (i, byte) in out[..len].iter_mut().enumerate() {
+        *byte = le_byte(a, len - 1 - i);
+    }
+
+    len
+}
+
+/// Reads big-endian `bytes` into `a`, which must be zeroed.
+///
+/// `bytes` may be of any length. Returns a falsy [`CtChoice`] if the value doesn't fit in `a`.
+pub(crate) fn from_be_bytes_padded(a: &mut [Limb], bytes: &[u8]) -> CtChoice {
+    let mut overflow = 0u8;
+
+    for (index, byte) in bytes.iter().rev().enumerate() {
+        match a.get_mut(index / Limb::BYTES) {
+            Some(limb) => limb.0 |= (*byte as Word) << ((index % Limb::BYTES) * 8),
+            None => overflow |= byte,
+        }
+    }
+
+    Limb(overflow as Word).ct_is_nonzero().not()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{modular::montgomery_reduction, Uint, U128, U256, U64};
+
+    const A: U256 =
+        U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+    const B: U128 = U128::from_be_hex("d5777c45019673125ad240f83094d425");
+
+    #[test]
+    fn add_n() {
+        let mut x = A;
+        let carry = super::add_n(x.as_limbs_mut(), B.as_limbs(), Limb::ONE);
+        let (expected, expected_carry) = A.adc(&B.resize(), Limb::ONE);
+        assert_eq!((x, carry), (expected, expected_carry));
+
+        let mut x = U256::MAX;
+        let carry = super::add_n(x.as_limbs_mut(), U64::ONE.as_limbs(), Limb::ZERO);
+        assert_eq!((x, carry), (U256::ZERO, Limb::ONE));
+    }
+
+    #[test]
+    fn sub_n() {
+        let mut x = A;
+        let borrow = super::sub_n(x.as_limbs_mut(), B.as_limbs(), Limb::MAX);
+        let (expected, expected_borrow) = A.sbb(&B.resize(), Limb::MAX);
+        assert_eq!((x, borrow), (expected, expected_borrow));
+
+        let mut x = U256::ZERO;
+        let borrow = super::sub_n(x.as_limbs_mut(), U64::ONE.as_limbs(), Limb::ZERO);
+        assert_eq!((x, borrow), (U256::MAX, Limb::MAX));
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_n_longer_rhs() {
+        let mut x = B;
+        super::add_n(x.as_limbs_mut(), A.as_limbs(), Limb::ZERO);
+    }
+
+    #[test]
+    fn mul_1() {
+        let mut x = A;
+        let carry = super::mul_1(x.as_limbs_mut(), Limb::MAX);
+        let (lo, hi) = A.mul_wide(&Uint::<1>::MAX);
+        assert_eq!((x, carry), (lo, hi.as_limbs()[0]));
+    }
+
+    #[test]
+    fn addmul_1() {
+        let mut x = A;
+        let carry = super::addmul_1(x.as_limbs_mut(), B.as_limbs(), Limb::MAX);
+
+        let (lo, hi) = B.mul_wide(&Uint::<1>::MAX);
+        let (sum, c) = lo.adc(&A.resize(), Limb::ZERO);
+        assert_eq!(x.resize::<{ U128::LIMBS }>(), sum);
+        assert_eq!(x.shr_vartime(128), A.shr_vartime(128));
+        assert_eq!(carry, hi.as_limbs()[0].wrapping_add(c));
+    }
+
+    #[test]
+    fn mul_basecase() {
+        let mut r = [Limb::ZERO; U256::LIMBS + U128::LIMBS];
+        super::mul_basecase(&mut r, A.as_limbs(), B.as_limbs());
+        let (lo, hi) = A.mul_wide(&B);
+        assert_eq!(&r[..U256::LIMBS], lo.as_limbs());
+        assert_eq!(&r[U256::LIMBS..], hi.as_limbs());
+    }
+
+    #[test]
+    fn sqr_basecase() {
+        for x in [A, U256::MAX, U256::ZERO, U256::ONE] {
+            let mut r = [Limb::ZERO; 2 * U256::LIMBS];
+            super::sqr_basecase(&mut r, x.as_limbs());
+            let (lo, hi) = x.square_wide();
+            assert_eq!(&r[..U256::LIMBS], lo.as_limbs());
+            assert_eq!(&r[U256::LIMBS..], hi.as_limbs());
+        }
+    }
+
+    #[test]
+    fn redc() {
+        let modulus = A;
+        let mod_neg_inv = Limb(
+            Word::MIN.wrapping_sub(modulus.inv_mod2k_vartime(Word::BITS as usize).as_limbs()[0].0),
+        );
+
+        for (lo, hi) in [
+            (U256::MAX, modulus.wrapping_sub(&U256::ONE)),
+            (B.resize(), U256::ZERO),
+            (U256::ONE, U256::ONE),
+        ] {
+            let expected = montgomery_reduction(&(lo, hi), &modulus, mod_neg_inv);
+
+            let mut t = [Limb::ZERO; 2 * U256::LIMBS];
+            t[..U256::LIMBS].copy_from_slice(lo.as_limbs());
+            t[U256::LIMBS..].copy_from_slice(hi.as_limbs());
+            let (t_lo, t_hi) = t.split_at_mut(U256::LIMBS);
+            super::redc(t_lo, t_hi, modulus.as_limbs(), mod_neg_inv);
+            assert_eq!(t_hi, expected.as_limbs());
+        }
+    }
+
+    #[test]
+    fn shl_n() {
+        for shift in 0..Limb::BITS {
+            let mut x = A;
+            let carry = super::shl_n(x.as_limbs_mut(), shift);
+            let (lo, hi) = U256::shl_vartime_wide((A, U256::ZERO), shift);
+            assert_eq!((x, carry), (lo, hi.as_limbs()[0]));
+        }
+    }
+
+    #[test]
+    fn shr_n() {
+        for shift in 0..Limb::BITS {
+            let mut x = A;
+            let carry = super::shr_n(x.as_limbs_mut(), shift);
+            let (lo, hi) = U256::shr_vartime_wide((U256::ZERO, A), shift);
+            assert_eq!((x, carry), (hi, lo.as_limbs()[U256::LIMBS - 1]));
+        }
+    }
+
+    #[test]
+    fn cmp_n() {
+        assert_eq!(super::cmp_n(A.as_limbs(), A.as_limbs()), Ordering::Equal);
+        assert_eq!(super::cmp_n(A.as_limbs(), B.as_limbs()), Ordering::Greater);
+        assert_eq!(super::cmp_n(B.as_limbs(), A.as_limbs()), Ordering::Less);
+        assert_eq!(
+            super::cmp_n(U64::ONE.as_limbs(), U256::ONE.as_limbs()),
+            Ordering::Equal
+        );
+        assert_eq!(super::cmp_n(&[], U256::ZERO.as_limbs()), Ordering::Equal);
+        assert_eq!(super::cmp_n(U64::ONE.as_limbs(), &[]), Ordering::Greater);
+    }
+}
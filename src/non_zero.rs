@@ -8,6 +8,9 @@ use core::{
 };
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
+#[cfg(feature = "der")]
+use ::der::{DecodeValue, EncodeValue, FixedTag, Length, Tag};
+
 #[cfg(feature = "generic-array")]
 use crate::{ArrayEncoding, ByteArray};
 
@@ -38,6 +41,11 @@ impl<const LIMBS: usize> NonZero<Uint<LIMBS>> {
     pub const fn const_new(n: Uint<LIMBS>) -> (Self, CtChoice) {
         (Self(n), n.ct_is_nonzero())
     }
+
+    /// Returns a reference to the inner [`Uint`] (const-friendly).
+    pub(crate) const fn as_uint(&self) -> &Uint<LIMBS> {
+        &self.0
+    }
 }
 
 impl<T> NonZero<T>
@@ -322,6 +330,44 @@ impl<const LIMBS: usize> From<NonZeroU128> for NonZero<Uint<LIMBS>> {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a> + Zero> arbitrary::Arbitrary<'a> for NonZero<T> {
+    /// Generate an arbitrary non-zero value, failing with
+    /// [`arbitrary::Error::IncorrectFormat`] if the generated value is zero.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let value = T::arbitrary(u)?;
+        Option::from(Self::new(value)).ok_or(arbitrary::Error::IncorrectFormat)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "der")]
+impl<'a, T: DecodeValue<'a> + Zero> DecodeValue<'a> for NonZero<T> {
+    fn decode_value<R: der::Reader<'a>>(reader: &mut R, header: der::Header) -> der::Result<Self> {
+        let value = T::decode_value(reader, header)?;
+        Option::from(Self::new(value)).ok_or_else(|| header.tag.value_error())
+    }
+}
+
+#[cfg(feature = "der")]
+impl<T: EncodeValue + Zero> EncodeValue for NonZero<T> {
+    fn value_len(&self) -> der::Result<Length> {
+        self.0.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut impl der::Writer) -> der::Result<()> {
+        self.0.encode_value(encoder)
+    }
+}
+
+#[cfg(feature = "der")]
+impl<T: FixedTag + Zero> FixedTag for NonZero<T> {
+    const TAG: Tag = T::TAG;
+}
+
 #[cfg(feature = "serde")]
 impl<'de, T: Deserialize<'de> + Zero> Deserialize<'de> for NonZero<T> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -351,12 +397,46 @@ impl<T: Serialize + Zero> Serialize for NonZero<T> {
     }
 }
 
-#[cfg(all(test, feature = "serde"))]
+#[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
+    #[cfg(any(
+        feature = "arbitrary",
+        feature = "serde",
+        all(feature = "der", feature = "generic-array")
+    ))]
     use crate::{NonZero, U64};
+
+    #[cfg(feature = "serde")]
     use bincode::ErrorKind;
 
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let n = NonZero::<U64>::arbitrary(&mut Unstructured::new(&[1; 8])).unwrap();
+        assert_eq!(n, NonZero::new(U64::from_u64(0x0101010101010101)).unwrap());
+        assert!(NonZero::<U64>::arbitrary(&mut Unstructured::new(&[0; 8])).is_err());
+    }
+
+    #[cfg(all(feature = "der", feature = "generic-array"))]
+    #[test]
+    fn der() {
+        use der::{Decode, Encode};
+        use hex_literal::hex;
+
+        let test = NonZero::new(U64::from_u64(0x80)).unwrap();
+        let mut buf = [0u8; 16];
+        let encoded = test.encode_to_slice(&mut buf).unwrap();
+        assert_eq!(encoded, &hex!("02020080"));
+        assert_eq!(NonZero::<U64>::from_der(encoded).unwrap(), test);
+
+        let err = NonZero::<U64>::from_der(&hex!("020100")).unwrap_err();
+        assert_eq!(err.kind(), der::Tag::Integer.value_error().kind());
+    }
+
+    #[cfg(feature = "serde")]
     #[test]
     fn serde() {
         let test =
@@ -374,6 +454,7 @@ mod tests {
         ));
     }
 
+    #[cfg(feature = "serde")]
     #[test]
     fn serde_owned() {
         let test =
@@ -0,0 +1,182 @@
+//! [`proptest`] strategies for generating integers and residues.
+//!
+//! Besides uniformly distributed values, the strategies in this module are biased towards
+//! edge cases such as zero, one, the maximum value, values with only the high bit set,
+//! and values around the modulus.
+//!
+//! [`Limb`][`crate::Limb`] and [`Uint`] also implement [`proptest::arbitrary::Arbitrary`], so they can be
+//! used with `any::<T>()`.
+
+use crate::{
+    modular::{
+        constant_mod::{Residue, ResidueParams},
+        runtime_mod::{DynResidue, DynResidueParams},
+    },
+    NonZero, Uint,
+};
+use proptest::{
+    arbitrary::any,
+    prop_oneof,
+    strategy::{Just, Strategy},
+};
+
+#[cfg(feature = "alloc")]
+use {
+    crate::{BoxedUint, Limb, Word},
+    core::ops::RangeInclusive,
+};
+
+/// Uniformly distributed [`Uint`]s.
+pub fn uint<const LIMBS: usize>() -> impl Strategy<Value = Uint<LIMBS>> {
+    any::<Uint<LIMBS>>()
+}
+
+/// [`Uint`]s biased towards edge cases: zero, one, the maximum value, a single set bit
+/// (in particular the high bit), and a mask of low bits.
+pub fn uint_edge_cases<const LIMBS: usize>() -> impl Strategy<Value = Uint<LIMBS>> {
+    let bits = Uint::<LIMBS>::BITS;
+    prop_oneof![
+        1 => Just(Uint::ZERO),
+        1 => Just(Uint::ONE),
+        1 => Just(Uint::MAX),
+        1 => Just(Uint::ONE.shl_vartime(bits - 1)),
+        1 => (0..bits).prop_map(|n| Uint::ONE.shl_vartime(n)),
+        1 => (0..bits).prop_map(|n| Uint::MAX.shr_vartime(n)),
+        4 => uint(),
+    ]
+}
+
+/// [`Uint`]s around `modulus`, not necessarily reduced: `modulus - 1`, `modulus` and
+/// `modulus + 1` (wrapping), along with the cases of [`uint_edge_cases`].
+pub fn uint_around<const LIMBS: usize>(modulus: Uint<LIMBS>) -> impl Strategy<Value = Uint<LIMBS>> {
+    prop_oneof![
+        1 => Just(modulus.wrapping_sub(&Uint::ONE)),
+        1 => Just(modulus),
+        1 => Just(modulus.wrapping_add(&Uint::ONE)),
+        3 => uint_edge_cases(),
+    ]
+}
+
+/// [`Uint`]s reduced modulo `modulus`, biased towards zero, one and `modulus - 1`.
+pub fn uint_mod<const LIMBS: usize>(
+    modulus: NonZero<Uint<LIMBS>>,
+) -> impl Strategy<Value = Uint<LIMBS>> {
+    let max = modulus.wrapping_sub(&Uint::ONE);
+    prop_oneof![
+        1 => Just(Uint::ZERO),
+        1 => Just(Uint::ONE.rem(&modulus)),
+        1 => Just(max),
+        4 => uint_edge_cases().prop_map(move |n| n.rem(&modulus)),
+    ]
+}
+
+/// Non-zero [`Uint`]s, biased towards edge cases like [`uint_edge_cases`].
+pub fn non_zero_uint<const LIMBS: usize>() -> impl Strategy<Value = NonZero<Uint<LIMBS>>> {
+    uint_edge_cases().prop_filter_map("zero", |n| NonZero::new(n).into())
+}
+
+/// [`Residue`]s modulo `MOD::MODULUS`, biased like [`uint_mod`].
+pub fn residue<MOD, const LIMBS: usize>() -> impl Strategy<Value = Residue<MOD, LIMBS>>
+where
+    MOD: ResidueParams<LIMBS>,
+{
+    uint_mod(NonZero::from_uint(MOD::MODULUS)).prop_map(|n| Residue::new(&n))
+}
+
+/// [`DynResidue`]s with the given parameters, biased like [`uint_mod`].
+pub fn dyn_residue<const LIMBS: usize>(
+    params: DynResidueParams<LIMBS>,
+) -> impl Strategy<Value = DynResidue<LIMBS>> {
+    uint_mod(NonZero::from_uint(*params.modulus())).prop_map(move |n| DynResidue::new(&n, params))
+}
+
+/// [`BoxedUint`]s with a precision in `bits`, which is rounded to the [`Limb`] size.
+///
+/// The values are biased towards zero, one, the maximum value and the high bit only.
+///
+/// # Panics
+///
+/// If `bits` doesn't contain a non-zero multiple of the [`Limb`] size.
+#[cfg(feature = "alloc")]
+pub fn boxed_uint(bits: RangeInclusive<usize>) -> impl Strategy<Value = BoxedUint> {
+    let min_limbs = ((*bits.start() + Limb::BITS - 1) / Limb::BITS).max(1);
+    let max_limbs = *bits.end() / Limb::BITS;
+    assert!(
+        min_limbs <= max_limbs,
+        "precision range doesn't contain a multiple of the limb size"
+    );
+
+    (min_limbs..=max_limbs).prop_flat_map(|nlimbs| {
+        let mut one = vec![0; nlimbs];
+        one[0] = 1;
+        let mut high_bit = vec![0; nlimbs];
+        high_bit[nlimbs - 1] = 1 << (Limb::BITS - 1);
+
+        prop_oneof![
+            1 => Just(BoxedUint::from_words(&vec![0; nlimbs])),
+            1 => Just(BoxedUint::from_words(&one)),
+            1 => Just(BoxedUint::from_words(&vec![Word::MAX; nlimbs])),
+            1 => Just(BoxedUint::from_words(&high_bit)),
+            4 => proptest::collection::vec(any::<Word>(), nlimbs)
+                .prop_map(|words| BoxedUint::from_words(&words)),
+        ]
+    })
+}
+
+// `proptest!` needs `format!`
+#[cfg(all(test, feature = "alloc"))]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{impl_modulus, U128, U256};
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+
+    impl_modulus!(
+        Modulus,
+        U256,
+        "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"
+    );
+
+    #[test]
+    fn edge_cases_are_generated() {
+        use proptest::{strategy::ValueTree, test_runner::TestRunner};
+
+        let mut runner = TestRunner::deterministic();
+        let strategy = uint_edge_cases::<{ U128::LIMBS }>();
+        let values: [U128; 256] =
+            core::array::from_fn(|_| strategy.new_tree(&mut runner).unwrap().current());
+
+        for expected in [U128::ZERO, U128::ONE, U128::MAX, U128::ONE.shl_vartime(127)] {
+            assert!(values.contains(&expected));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn uint_mod_is_reduced(n in uint_mod(NonZero::from_uint(Modulus::MODULUS))) {
+            prop_assert!(n < Modulus::MODULUS);
+        }
+
+        #[test]
+        fn non_zero(n in non_zero_uint::<{ U128::LIMBS }>()) {
+            prop_assert!(*n != U128::ZERO);
+        }
+
+        #[test]
+        fn residue_is_reduced(r in residue::<Modulus, { U256::LIMBS }>()) {
+            prop_assert!(r.retrieve() < Modulus::MODULUS);
+        }
+
+        #[test]
+        fn dyn_residue_params(r in dyn_residue(DynResidueParams::new(&Modulus::MODULUS))) {
+            prop_assert_eq!(r.params().modulus(), &Modulus::MODULUS);
+            prop_assert!(r.retrieve() < Modulus::MODULUS);
+        }
+
+        #[test]
+        fn boxed_precision(n in boxed_uint(64..=256)) {
+            prop_assert!(n.bits() >= 64 && n.bits() <= 256);
+            prop_assert_eq!(n.bits() % Limb::BITS, 0);
+        }
+    }
+}
@@ -1,8 +1,8 @@
 //! Traits provided by this crate
 
-use crate::{Limb, NonZero};
+use crate::{Limb, NonZero, Word};
 use core::fmt::Debug;
-use core::ops::{BitAnd, BitOr, BitXor, Div, Not, Rem, Shl, Shr};
+use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Not, Rem, Shl, Shr, Sub};
 use subtle::{
     Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
     CtOption,
@@ -11,34 +11,112 @@ use subtle::{
 #[cfg(feature = "rand_core")]
 use rand_core::CryptoRngCore;
 
+/// Integers and residues whose precision may only be known at runtime.
+///
+/// This is the common base of fixed-precision types like [`Uint`][`crate::Uint`] and
+/// heap-allocated ones like `BoxedUint`, so unlike [`Integer`] it doesn't require [`Copy`]
+/// or compile-time constants: values of the same precision are derived from an existing one.
+pub trait UintLike: 'static + Clone + ConstantTimeEq + Debug + Eq + Send + Sync + Sized {
+    /// Precision of this value in bits.
+    fn bits_precision(&self) -> usize;
+
+    /// Precision of this value in limbs.
+    fn nlimbs(&self) -> usize {
+        self.bits_precision() / Limb::BITS
+    }
+
+    /// The value `0` with the same precision as `self`.
+    fn zero_like(&self) -> Self;
+
+    /// The value `1` with the same precision as `self`.
+    fn one_like(&self) -> Self;
+}
+
+/// Unsigned integers with fixed or runtime precision.
+///
+/// Operations take their operands by reference, and expect them to have the same precision.
+///
+/// The `+` and `-` operators panic on overflow; use [`Unsigned::wrapping_add`] and friends or
+/// the [`CheckedAdd`] family to handle it explicitly.
+pub trait Unsigned:
+    UintLike
+    + AsRef<[Limb]>
+    + for<'a> Add<&'a Self, Output = Self>
+    + for<'a> Sub<&'a Self, Output = Self>
+    + Div<NonZero<Self>, Output = Self>
+    + Rem<NonZero<Self>, Output = Self>
+    + for<'a> CheckedAdd<&'a Self, Output = Self>
+    + for<'a> CheckedSub<&'a Self, Output = Self>
+    + for<'a> CheckedMul<&'a Self, Output = Self>
+    + ConstantTimeGreater
+    + ConstantTimeLess
+    + Ord
+    + Zero
+{
+    /// Convert a [`Word`] into a value with the same precision as `other`.
+    fn from_word_like(word: Word, other: &Self) -> Self;
+
+    /// Is this integer value an odd number?
+    ///
+    /// # Returns
+    ///
+    /// If odd, returns `Choice(1)`. Otherwise, returns `Choice(0)`.
+    fn is_odd(&self) -> Choice;
+
+    /// Is this integer value an even number?
+    ///
+    /// # Returns
+    ///
+    /// If even, returns `Choice(1)`. Otherwise, returns `Choice(0)`.
+    fn is_even(&self) -> Choice {
+        !self.is_odd()
+    }
+
+    /// Returns `true` if the bit at position `index` is set, `false` otherwise.
+    ///
+    /// # Remarks
+    /// This operation is variable time with respect to `index` only.
+    fn bit_vartime(&self, index: usize) -> bool;
+
+    /// Calculate the number of bits needed to represent this number.
+    fn bits_vartime(&self) -> usize;
+
+    /// Perform wrapping addition, discarding overflow.
+    fn wrapping_add(&self, rhs: &Self) -> Self;
+
+    /// Perform wrapping subtraction, discarding underflow and wrapping around the boundary
+    /// of the type.
+    fn wrapping_sub(&self, rhs: &Self) -> Self;
+
+    /// Perform wrapping multiplication, discarding overflow.
+    fn wrapping_mul(&self, rhs: &Self) -> Self;
+
+    /// Computes `self << shift`, discarding the bits shifted out.
+    ///
+    /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
+    fn shl_vartime(&self, shift: usize) -> Self;
+
+    /// Computes `self >> shift`.
+    ///
+    /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
+    fn shr_vartime(&self, shift: usize) -> Self;
+}
+
 /// Integer type.
 pub trait Integer:
-    'static
-    + AsRef<[Limb]>
+    Unsigned
     + BitAnd<Output = Self>
     + BitOr<Output = Self>
     + BitXor<Output = Self>
-    + for<'a> CheckedAdd<&'a Self, Output = Self>
-    + for<'a> CheckedSub<&'a Self, Output = Self>
-    + for<'a> CheckedMul<&'a Self, Output = Self>
     + Copy
     + ConditionallySelectable
-    + ConstantTimeEq
-    + ConstantTimeGreater
-    + ConstantTimeLess
-    + Debug
     + Default
     + Div<NonZero<Self>, Output = Self>
-    + Eq
     + From<u64>
     + Not
-    + Ord
     + Rem<NonZero<Self>, Output = Self>
-    + Send
-    + Sized
     + Shl<usize, Output = Self>
     + Shr<usize, Output = Self>
-    + Sync
     + Zero
 {
     /// The value `1`.
@@ -58,18 +136,24 @@ pub trait Integer:
 
     /// Is this integer value an odd number?
     ///
+    /// Forwards to [`Unsigned::is_odd`].
+    ///
     /// # Returns
     ///
     /// If odd, returns `Choice(1)`. Otherwise, returns `Choice(0)`.
-    fn is_odd(&self) -> Choice;
+    fn is_odd(&self) -> Choice {
+        Unsigned::is_odd(self)
+    }
 
     /// Is this integer value an even number?
     ///
+    /// Forwards to [`Unsigned::is_even`].
+    ///
     /// # Returns
     ///
     /// If even, returns `Choice(1)`. Otherwise, returns `Choice(0)`.
     fn is_even(&self) -> Choice {
-        !self.is_odd()
+        Unsigned::is_even(self)
     }
 }
 
@@ -301,6 +385,13 @@ where
 {
     /// Calculates `x1 ^ k1 * ... * xn ^ kn`.
     fn multi_exponentiate(bases_and_exponents: &BasesAndExponents) -> Self;
+
+    /// Calculates `x1 ^ k1 * ... * xn ^ kn` in variable time with respect to the exponents.
+    ///
+    /// Defaults to [`MultiExponentiate::multi_exponentiate`].
+    fn multi_exponentiate_vartime(bases_and_exponents: &BasesAndExponents) -> Self {
+        Self::multi_exponentiate(bases_and_exponents)
+    }
 }
 
 impl<T, Exponent, BasesAndExponents> MultiExponentiate<Exponent, BasesAndExponents> for T
@@ -312,6 +403,10 @@ where
     fn multi_exponentiate(bases_and_exponents: &BasesAndExponents) -> Self {
         Self::multi_exponentiate_bounded_exp(bases_and_exponents, Exponent::BITS)
     }
+
+    fn multi_exponentiate_vartime(bases_and_exponents: &BasesAndExponents) -> Self {
+        Self::multi_exponentiate_bounded_exp_vartime(bases_and_exponents, Exponent::BITS)
+    }
 }
 
 /// Performs modular multi-exponentiation using Montgomery's ladder.
@@ -329,6 +424,16 @@ where
         bases_and_exponents: &BasesAndExponents,
         exponent_bits: usize,
     ) -> Self;
+
+    /// Calculates `x1 ^ k1 * ... * xn ^ kn` in variable time with respect to the exponents.
+    ///
+    /// Defaults to [`MultiExponentiateBoundedExp::multi_exponentiate_bounded_exp`].
+    fn multi_exponentiate_bounded_exp_vartime(
+        bases_and_exponents: &BasesAndExponents,
+        exponent_bits: usize,
+    ) -> Self {
+        Self::multi_exponentiate_bounded_exp(bases_and_exponents, exponent_bits)
+    }
 }
 
 /// Constant-time inversion.
@@ -339,3 +444,72 @@ pub trait Invert: Sized {
     /// Computes the inverse.
     fn invert(&self) -> Self::Output;
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{UintLike, Unsigned};
+    use crate::{
+        modular::runtime_mod::{DynResidue, DynResidueParams},
+        Limb, NonZero, Word, U256,
+    };
+
+    /// Decompose `n - 1` as `2^s * d` with `d` odd, as in the Miller-Rabin test.
+    fn decompose<T: Unsigned>(n: &T) -> (usize, T) {
+        let d = n.wrapping_sub(&n.one_like());
+        let s = (0..d.bits_precision())
+            .find(|&i| d.bit_vartime(i))
+            .unwrap_or(0);
+        (s, d.shr_vartime(s))
+    }
+
+    /// Split `n` into its quotient and remainder by `word`, checking the operators agree.
+    fn split<T: Unsigned>(n: &T, word: Word) -> (T, T) {
+        let divisor = NonZero::new(T::from_word_like(word, n)).unwrap();
+        let quo = n.clone() / divisor.clone();
+        let rem = n.clone() % divisor;
+        assert_eq!(n.clone() - &rem + &rem, *n);
+        (quo, rem)
+    }
+
+    const N: U256 =
+        U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+
+    #[test]
+    fn generic_uint() {
+        let (s, d) = decompose(&N);
+        assert_eq!(s, 4);
+        assert!(bool::from(d.is_odd()));
+        assert_eq!(d.shl_vartime(s).wrapping_add(&U256::ONE), N);
+        assert_eq!(Unsigned::from_word_like(3, &N), U256::from(3u8));
+        let (quo, rem) = N.div_rem_limb(NonZero::new(Limb(10)).unwrap());
+        assert_eq!(split(&N, 10), (quo, U256::from(rem)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn generic_boxed_uint() {
+        use crate::BoxedUint;
+
+        let n = BoxedUint::from_words(N.as_words());
+        let (s, d) = decompose(&n);
+        assert_eq!(s, 4);
+        assert_eq!(d.as_words(), decompose(&N).1.as_words());
+        assert_eq!(n.bits_precision(), 256);
+        assert_eq!(n.one_like().bits_precision(), 256);
+
+        let (quo, rem) = split(&n, 10);
+        let (expected_quo, expected_rem) = split(&N, 10);
+        assert_eq!(quo.as_words(), expected_quo.as_words());
+        assert_eq!(rem.as_words(), expected_rem.as_words());
+    }
+
+    #[test]
+    fn generic_residue() {
+        let params = DynResidueParams::new(&N);
+        let x = DynResidue::new(&U256::from(5u8), params);
+        assert_eq!(x.bits_precision(), 256);
+        assert_eq!(x.one_like(), DynResidue::one(params));
+        assert_eq!(x.zero_like(), DynResidue::zero(params));
+    }
+}
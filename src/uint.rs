@@ -23,6 +23,7 @@ mod mul;
 mod mul_mod;
 mod neg;
 mod neg_mod;
+mod pow;
 mod resize;
 mod shl;
 mod shr;
@@ -37,10 +38,15 @@ pub mod modular;
 #[cfg(feature = "generic-array")]
 mod array;
 
+#[cfg(feature = "bytemuck")]
+mod cast;
+
 #[cfg(feature = "rand_core")]
 mod rand;
 
-use crate::{Bounded, Encoding, Integer, Limb, Word, Zero};
+pub use encoding::HexError;
+
+use crate::{Bounded, Checked, Encoding, Integer, Limb, UintLike, Unsigned, Word, Wrapping, Zero};
 use core::fmt;
 use subtle::{Choice, ConditionallySelectable};
 
@@ -64,12 +70,15 @@ use zeroize::DefaultIsZeroes;
 ///   [`Uint`] as `GenericArray<u8, N>` and a [`ArrayDecoding`][`crate::ArrayDecoding`] trait which
 ///   can be used to `GenericArray<u8, N>` as [`Uint`].
 /// - `rlp`: support for [Recursive Length Prefix (RLP)][RLP] encoding.
+/// - `bytemuck`: implements `Pod` and `Zeroable`, and enables zero-copy casts from slices of
+///   [`Word`]s and (on little endian targets) bytes.
 ///
 /// [RLP]: https://eth.wiki/fundamentals/rlp
 // TODO(tarcieri): make generic around a specified number of bits.
 // Our PartialEq impl only differs from the default one by being constant-time, so this is safe
 #[allow(clippy::derived_hash_with_manual_eq)]
 #[derive(Copy, Clone, Hash)]
+#[repr(transparent)]
 pub struct Uint<const LIMBS: usize> {
     /// Inner limb array. Stored from least significant to most significant.
     limbs: [Limb; LIMBS],
@@ -211,21 +220,119 @@ impl<const LIMBS: usize> Default for Uint<LIMBS> {
     }
 }
 
+impl<const LIMBS: usize> UintLike for Uint<LIMBS> {
+    fn bits_precision(&self) -> usize {
+        Self::BITS
+    }
+
+    fn nlimbs(&self) -> usize {
+        LIMBS
+    }
+
+    fn zero_like(&self) -> Self {
+        Self::ZERO
+    }
+
+    fn one_like(&self) -> Self {
+        Self::ONE
+    }
+}
+
+impl<const LIMBS: usize> Unsigned for Uint<LIMBS> {
+    fn from_word_like(word: Word, _other: &Self) -> Self {
+        Self::from_word(word)
+    }
+
+    fn is_odd(&self) -> Choice {
+        self.limbs
+            .first()
+            .map(|limb| limb.is_odd())
+            .unwrap_or_else(|| Choice::from(0))
+    }
+
+    fn bit_vartime(&self, index: usize) -> bool {
+        self.bit_vartime(index)
+    }
+
+    fn bits_vartime(&self) -> usize {
+        self.bits_vartime()
+    }
+
+    fn wrapping_add(&self, rhs: &Self) -> Self {
+        self.wrapping_add(rhs)
+    }
+
+    fn wrapping_sub(&self, rhs: &Self) -> Self {
+        self.wrapping_sub(rhs)
+    }
+
+    fn wrapping_mul(&self, rhs: &Self) -> Self {
+        self.wrapping_mul(rhs)
+    }
+
+    fn shl_vartime(&self, shift: usize) -> Self {
+        self.shl_vartime(shift)
+    }
+
+    fn shr_vartime(&self, shift: usize) -> Self {
+        self.shr_vartime(shift)
+    }
+}
+
 impl<const LIMBS: usize> Integer for Uint<LIMBS> {
     const ONE: Self = Self::ONE;
     const MAX: Self = Self::MAX;
     const BITS: usize = Self::BITS;
     const BYTES: usize = Self::BYTES;
     const LIMBS: usize = Self::LIMBS;
+}
+
+impl<const LIMBS: usize> Unsigned for Wrapping<Uint<LIMBS>> {
+    fn from_word_like(word: Word, _other: &Self) -> Self {
+        Wrapping(Uint::from_word(word))
+    }
 
     fn is_odd(&self) -> Choice {
-        self.limbs
-            .first()
-            .map(|limb| limb.is_odd())
-            .unwrap_or_else(|| Choice::from(0))
+        Unsigned::is_odd(&self.0)
+    }
+
+    fn bit_vartime(&self, index: usize) -> bool {
+        self.0.bit_vartime(index)
+    }
+
+    fn bits_vartime(&self) -> usize {
+        self.0.bits_vartime()
+    }
+
+    fn wrapping_add(&self, rhs: &Self) -> Self {
+        Wrapping(self.0.wrapping_add(&rhs.0))
+    }
+
+    fn wrapping_sub(&self, rhs: &Self) -> Self {
+        Wrapping(self.0.wrapping_sub(&rhs.0))
+    }
+
+    fn wrapping_mul(&self, rhs: &Self) -> Self {
+        Wrapping(self.0.wrapping_mul(&rhs.0))
+    }
+
+    fn shl_vartime(&self, shift: usize) -> Self {
+        Wrapping(self.0.shl_vartime(shift))
+    }
+
+    fn shr_vartime(&self, shift: usize) -> Self {
+        Wrapping(self.0.shr_vartime(shift))
     }
 }
 
+impl<const LIMBS: usize> Integer for Wrapping<Uint<LIMBS>> {
+    const ONE: Self = Wrapping(Uint::ONE);
+    const MAX: Self = Wrapping(Uint::MAX);
+    const BITS: usize = Uint::<LIMBS>::BITS;
+    const BYTES: usize = Uint::<LIMBS>::BYTES;
+    const LIMBS: usize = LIMBS;
+}
+
 impl<const LIMBS: usize> Zero for Uint<LIMBS> {
     const ZERO: Self = Self::ZERO;
 }
@@ -265,6 +372,33 @@ impl<const LIMBS: usize> fmt::UpperHex for Uint<LIMBS> {
     }
 }
 
+impl<const LIMBS: usize> fmt::Binary for Uint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for limb in self.limbs.iter().rev() {
+            fmt::Binary::fmt(limb, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const LIMBS: usize> fmt::Octal for Uint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Octal digits straddle limb boundaries, so they are extracted 3 bits at a time
+        let mut i = (Self::BITS + 2) / 3;
+        while i > 0 {
+            i -= 1;
+            let mut digit = 0;
+            let mut j = 3;
+            while j > 0 {
+                j -= 1;
+                digit = (digit << 1) | (self.bit_vartime(i * 3 + j) as u8);
+            }
+            fmt::Write::write_char(f, char::from(b'0' + digit))?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(feature = "serde")]
 impl<'de, const LIMBS: usize> Deserialize<'de> for Uint<LIMBS>
 where
@@ -297,6 +431,30 @@ where
 #[cfg(feature = "zeroize")]
 impl<const LIMBS: usize> DefaultIsZeroes for Uint<LIMBS> {}
 
+#[cfg(feature = "arbitrary")]
+impl<'a, const LIMBS: usize> arbitrary::Arbitrary<'a> for Uint<LIMBS> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        <[Limb; LIMBS] as arbitrary::Arbitrary<'a>>::arbitrary(u).map(Self::new)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[Limb; LIMBS] as arbitrary::Arbitrary<'a>>::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<const LIMBS: usize> proptest::arbitrary::Arbitrary for Uint<LIMBS> {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+        proptest::array::uniform::<_, LIMBS>(proptest::arbitrary::any::<Limb>())
+            .prop_map(Self::new)
+            .boxed()
+    }
+}
+
 // TODO(tarcieri): use `generic_const_exprs` when stable to make generic around bits.
 impl_uint_aliases! {
     (U64, 64, "64-bit"),
@@ -402,7 +560,7 @@ mod tests {
     #[cfg(feature = "alloc")]
     use alloc::format;
 
-    #[cfg(feature = "serde")]
+    #[cfg(any(feature = "alloc", feature = "serde"))]
     use crate::U64;
 
     #[cfg(feature = "alloc")]
@@ -439,6 +597,31 @@ mod tests {
         assert_eq!(hex, n.to_string());
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn binary_octal() {
+        let n = U64::from_u64(0x0123_4567_89AB_CDEF);
+        assert_eq!(
+            format!("{n:b}"),
+            format!("{:064b}", 0x0123_4567_89AB_CDEFu64)
+        );
+        assert_eq!(
+            format!("{n:o}"),
+            format!("{:022o}", 0x0123_4567_89AB_CDEFu64)
+        );
+
+        let n = U128::from_u128(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+        assert_eq!(
+            format!("{n:b}"),
+            format!("{:0128b}", 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128)
+        );
+        assert_eq!(
+            format!("{n:o}"),
+            format!("{:043o}", 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128)
+        );
+        assert_eq!(format!("{:o}", U128::MAX), format!("{:o}", u128::MAX));
+    }
+
     #[test]
     fn from_bytes() {
         let a = U128::from_be_hex("AAAAAAAABBBBBBBB0CCCCCCCDDDDDDDD");
@@ -1,6 +1,6 @@
 //! [`Uint`] addition operations.
 
-use crate::{Checked, CheckedAdd, CtChoice, Limb, Uint, Wrapping, Zero};
+use crate::{limb_slice, Checked, CheckedAdd, CtChoice, Limb, Uint, Wrapping, Zero};
 use core::ops::{Add, AddAssign};
 use subtle::CtOption;
 
@@ -21,6 +21,12 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         (Self { limbs }, carry)
     }
 
+    /// Computes `self += rhs + carry` in place, returning the new carry.
+    #[inline(always)]
+    pub fn adc_assign(&mut self, rhs: &Self, carry: Limb) -> Limb {
+        limb_slice::add_n(&mut self.limbs, &rhs.limbs, carry)
+    }
+
     /// Perform saturating addition, returning `MAX` on overflow.
     pub const fn saturating_add(&self, rhs: &Self) -> Self {
         let (res, overflow) = self.adc(rhs, Limb::ZERO);
@@ -32,6 +38,13 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         self.adc(rhs, Limb::ZERO).0
     }
 
+    /// Computes `self + rhs`, returning the result along with [`CtChoice::TRUE`]
+    /// if an overflow occurred.
+    pub const fn overflowing_add(&self, rhs: &Self) -> (Self, CtChoice) {
+        let (res, carry) = self.adc(rhs, Limb::ZERO);
+        (res, CtChoice::from_lsb(carry.0))
+    }
+
     /// Perform wrapping addition, returning the truthy value as the second element of the tuple
     /// if an overflow has occurred.
     pub(crate) const fn conditional_wrapping_add(
@@ -54,6 +67,31 @@ impl<const LIMBS: usize> CheckedAdd<&Uint<LIMBS>> for Uint<LIMBS> {
     }
 }
 
+impl<const LIMBS: usize> Add for Uint<LIMBS> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.add(&rhs)
+    }
+}
+
+impl<const LIMBS: usize> Add<&Uint<LIMBS>> for Uint<LIMBS> {
+    type Output = Self;
+
+    fn add(self, rhs: &Self) -> Self {
+        self.checked_add(rhs)
+            .expect("attempted to add with overflow")
+    }
+}
+
+impl<const LIMBS: usize> CheckedAdd<&Wrapping<Uint<LIMBS>>> for Wrapping<Uint<LIMBS>> {
+    type Output = Self;
+
+    fn checked_add(&self, rhs: &Self) -> CtOption<Self> {
+        self.0.checked_add(&rhs.0).map(Wrapping)
+    }
+}
+
 impl<const LIMBS: usize> Add for Wrapping<Uint<LIMBS>> {
     type Output = Self;
 
@@ -172,6 +210,18 @@ mod tests {
         assert_eq!(carry, Limb::ONE);
     }
 
+    #[test]
+    fn adc_assign() {
+        let mut x = U128::MAX;
+        let carry = x.adc_assign(&U128::ONE, Limb::ZERO);
+        assert_eq!(x, U128::ZERO);
+        assert_eq!(carry, Limb::ONE);
+
+        let carry = x.adc_assign(&U128::ONE, Limb::ONE);
+        assert_eq!(x, U128::from_u8(2));
+        assert_eq!(carry, Limb::ZERO);
+    }
+
     #[test]
     fn saturating_add_no_carry() {
         assert_eq!(U128::ZERO.saturating_add(&U128::ONE), U128::ONE);
@@ -203,4 +253,26 @@ mod tests {
         let result = U128::MAX.checked_add(&U128::ONE);
         assert!(!bool::from(result.is_some()));
     }
+
+    #[test]
+    fn add_ok() {
+        assert_eq!(U128::ZERO + U128::ONE, U128::ONE);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to add with overflow")]
+    fn add_overflow() {
+        let _ = U128::MAX + U128::ONE;
+    }
+
+    #[test]
+    fn overflowing_add() {
+        let (res, overflow) = U128::ONE.overflowing_add(&U128::ONE);
+        assert_eq!(res, U128::from(2u8));
+        assert!(!bool::from(overflow));
+
+        let (res, overflow) = U128::MAX.overflowing_add(&U128::from(2u8));
+        assert_eq!(res, U128::ONE);
+        assert!(bool::from(overflow));
+    }
 }
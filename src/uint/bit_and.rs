@@ -1,7 +1,7 @@
 //! [`Uint`] bitwise and operations.
 
 use super::Uint;
-use crate::{Limb, Wrapping};
+use crate::{Checked, Limb, Wrapping};
 use core::ops::{BitAnd, BitAndAssign};
 use subtle::{Choice, CtOption};
 
@@ -128,9 +128,55 @@ impl<const LIMBS: usize> BitAndAssign<&Wrapping<Uint<LIMBS>>> for Wrapping<Uint<
     }
 }
 
+impl<const LIMBS: usize> BitAnd for Checked<Uint<LIMBS>> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Checked<Uint<LIMBS>> {
+        Checked(self.0.and_then(|lhs| rhs.0.map(|rhs| lhs.bitand(&rhs))))
+    }
+}
+
+impl<const LIMBS: usize> BitAnd<&Checked<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    type Output = Checked<Uint<LIMBS>>;
+
+    fn bitand(self, rhs: &Checked<Uint<LIMBS>>) -> Checked<Uint<LIMBS>> {
+        Checked(self.0.and_then(|lhs| rhs.0.map(|rhs| lhs.bitand(&rhs))))
+    }
+}
+
+impl<const LIMBS: usize> BitAnd<Checked<Uint<LIMBS>>> for &Checked<Uint<LIMBS>> {
+    type Output = Checked<Uint<LIMBS>>;
+
+    fn bitand(self, rhs: Checked<Uint<LIMBS>>) -> Checked<Uint<LIMBS>> {
+        Checked(self.0.and_then(|lhs| rhs.0.map(|rhs| lhs.bitand(&rhs))))
+    }
+}
+
+impl<const LIMBS: usize> BitAnd<&Checked<Uint<LIMBS>>> for &Checked<Uint<LIMBS>> {
+    type Output = Checked<Uint<LIMBS>>;
+
+    fn bitand(self, rhs: &Checked<Uint<LIMBS>>) -> Checked<Uint<LIMBS>> {
+        Checked(self.0.and_then(|lhs| rhs.0.map(|rhs| lhs.bitand(&rhs))))
+    }
+}
+
+impl<const LIMBS: usize> BitAndAssign for Checked<Uint<LIMBS>> {
+    #[allow(clippy::assign_op_pattern)]
+    fn bitand_assign(&mut self, other: Self) {
+        *self = *self & other;
+    }
+}
+
+impl<const LIMBS: usize> BitAndAssign<&Checked<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    #[allow(clippy::assign_op_pattern)]
+    fn bitand_assign(&mut self, other: &Self) {
+        *self = *self & other;
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::U128;
+    use crate::{Checked, U128};
 
     #[test]
     fn checked_and_ok() {
@@ -143,4 +189,14 @@ mod tests {
         let result = U128::MAX.wrapping_and(&U128::ONE);
         assert_eq!(result, U128::ONE);
     }
+
+    #[test]
+    fn checked_and_propagates() {
+        let result = Checked::new(U128::MAX) & Checked::new(U128::ONE);
+        assert_eq!(result.0.unwrap(), U128::ONE);
+
+        let failed = Checked::new(U128::ZERO) - Checked::new(U128::ONE);
+        assert!(bool::from((failed & Checked::new(U128::ONE)).0.is_none()));
+        assert!(bool::from((Checked::new(U128::ONE) & failed).0.is_none()));
+    }
 }
@@ -1,7 +1,7 @@
 //! [`Uint`] bitwise not operations.
 
 use super::Uint;
-use crate::{Limb, Wrapping};
+use crate::{Checked, Limb, Wrapping};
 use core::ops::Not;
 
 impl<const LIMBS: usize> Uint<LIMBS> {
@@ -37,13 +37,29 @@ impl<const LIMBS: usize> Not for Wrapping<Uint<LIMBS>> {
     }
 }
 
+impl<const LIMBS: usize> Not for Checked<Uint<LIMBS>> {
+    type Output = Self;
+
+    fn not(self) -> <Self as Not>::Output {
+        Checked(self.0.map(|x| x.not()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::U128;
+    use crate::{Checked, U128};
 
     #[test]
     fn bitnot_ok() {
         assert_eq!(U128::ZERO.not(), U128::MAX);
         assert_eq!(U128::MAX.not(), U128::ZERO);
     }
+
+    #[test]
+    fn checked_not() {
+        assert_eq!((!Checked::new(U128::ZERO)).0.unwrap(), U128::MAX);
+
+        let failed = Checked::new(U128::ZERO) - Checked::new(U128::ONE);
+        assert!(bool::from((!failed).0.is_none()));
+    }
 }
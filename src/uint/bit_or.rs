@@ -1,7 +1,7 @@
 //! [`Uint`] bitwise or operations.
 
 use super::Uint;
-use crate::{Limb, Wrapping};
+use crate::{Checked, Limb, Wrapping};
 use core::ops::{BitOr, BitOrAssign};
 use subtle::{Choice, CtOption};
 
@@ -124,9 +124,53 @@ impl<const LIMBS: usize> BitOrAssign<&Wrapping<Uint<LIMBS>>> for Wrapping<Uint<L
     }
 }
 
+impl<const LIMBS: usize> BitOr for Checked<Uint<LIMBS>> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Checked<Uint<LIMBS>> {
+        Checked(self.0.and_then(|lhs| rhs.0.map(|rhs| lhs.bitor(&rhs))))
+    }
+}
+
+impl<const LIMBS: usize> BitOr<&Checked<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    type Output = Checked<Uint<LIMBS>>;
+
+    fn bitor(self, rhs: &Checked<Uint<LIMBS>>) -> Checked<Uint<LIMBS>> {
+        Checked(self.0.and_then(|lhs| rhs.0.map(|rhs| lhs.bitor(&rhs))))
+    }
+}
+
+impl<const LIMBS: usize> BitOr<Checked<Uint<LIMBS>>> for &Checked<Uint<LIMBS>> {
+    type Output = Checked<Uint<LIMBS>>;
+
+    fn bitor(self, rhs: Checked<Uint<LIMBS>>) -> Checked<Uint<LIMBS>> {
+        Checked(self.0.and_then(|lhs| rhs.0.map(|rhs| lhs.bitor(&rhs))))
+    }
+}
+
+impl<const LIMBS: usize> BitOr<&Checked<Uint<LIMBS>>> for &Checked<Uint<LIMBS>> {
+    type Output = Checked<Uint<LIMBS>>;
+
+    fn bitor(self, rhs: &Checked<Uint<LIMBS>>) -> Checked<Uint<LIMBS>> {
+        Checked(self.0.and_then(|lhs| rhs.0.map(|rhs| lhs.bitor(&rhs))))
+    }
+}
+
+impl<const LIMBS: usize> BitOrAssign for Checked<Uint<LIMBS>> {
+    fn bitor_assign(&mut self, other: Self) {
+        *self = *self | other;
+    }
+}
+
+impl<const LIMBS: usize> BitOrAssign<&Checked<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    fn bitor_assign(&mut self, other: &Self) {
+        *self = *self | other;
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::U128;
+    use crate::{Checked, U128};
 
     #[test]
     fn checked_or_ok() {
@@ -139,4 +183,14 @@ mod tests {
         let result = U128::MAX.wrapping_or(&U128::ONE);
         assert_eq!(result, U128::MAX);
     }
+
+    #[test]
+    fn checked_or_propagates() {
+        let result = Checked::new(U128::MAX) | Checked::new(U128::ONE);
+        assert_eq!(result.0.unwrap(), U128::MAX);
+
+        let failed = Checked::new(U128::ZERO) - Checked::new(U128::ONE);
+        assert!(bool::from((failed | Checked::new(U128::ONE)).0.is_none()));
+        assert!(bool::from((Checked::new(U128::ONE) | failed).0.is_none()));
+    }
 }
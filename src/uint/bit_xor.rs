@@ -1,7 +1,7 @@
 //! [`Uint`] bitwise xor operations.
 
 use super::Uint;
-use crate::{Limb, Wrapping};
+use crate::{Checked, Limb, Wrapping};
 use core::ops::{BitXor, BitXorAssign};
 use subtle::{Choice, CtOption};
 
@@ -124,9 +124,53 @@ impl<const LIMBS: usize> BitXorAssign<&Wrapping<Uint<LIMBS>>> for Wrapping<Uint<
     }
 }
 
+impl<const LIMBS: usize> BitXor for Checked<Uint<LIMBS>> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Checked<Uint<LIMBS>> {
+        Checked(self.0.and_then(|lhs| rhs.0.map(|rhs| lhs.bitxor(&rhs))))
+    }
+}
+
+impl<const LIMBS: usize> BitXor<&Checked<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    type Output = Checked<Uint<LIMBS>>;
+
+    fn bitxor(self, rhs: &Checked<Uint<LIMBS>>) -> Checked<Uint<LIMBS>> {
+        Checked(self.0.and_then(|lhs| rhs.0.map(|rhs| lhs.bitxor(&rhs))))
+    }
+}
+
+impl<const LIMBS: usize> BitXor<Checked<Uint<LIMBS>>> for &Checked<Uint<LIMBS>> {
+    type Output = Checked<Uint<LIMBS>>;
+
+    fn bitxor(self, rhs: Checked<Uint<LIMBS>>) -> Checked<Uint<LIMBS>> {
+        Checked(self.0.and_then(|lhs| rhs.0.map(|rhs| lhs.bitxor(&rhs))))
+    }
+}
+
+impl<const LIMBS: usize> BitXor<&Checked<Uint<LIMBS>>> for &Checked<Uint<LIMBS>> {
+    type Output = Checked<Uint<LIMBS>>;
+
+    fn bitxor(self, rhs: &Checked<Uint<LIMBS>>) -> Checked<Uint<LIMBS>> {
+        Checked(self.0.and_then(|lhs| rhs.0.map(|rhs| lhs.bitxor(&rhs))))
+    }
+}
+
+impl<const LIMBS: usize> BitXorAssign for Checked<Uint<LIMBS>> {
+    fn bitxor_assign(&mut self, other: Self) {
+        *self = *self ^ other;
+    }
+}
+
+impl<const LIMBS: usize> BitXorAssign<&Checked<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    fn bitxor_assign(&mut self, other: &Self) {
+        *self = *self ^ other;
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::U128;
+    use crate::{Checked, U128};
 
     #[test]
     fn checked_xor_ok() {
@@ -139,4 +183,14 @@ mod tests {
         let result = U128::ZERO.wrapping_xor(&U128::ONE);
         assert_eq!(result, U128::ONE);
     }
+
+    #[test]
+    fn checked_xor_propagates() {
+        let result = Checked::new(U128::MAX) ^ Checked::new(U128::ONE);
+        assert_eq!(result.0.unwrap(), U128::MAX.wrapping_sub(&U128::ONE));
+
+        let failed = Checked::new(U128::ZERO) - Checked::new(U128::ONE);
+        assert!(bool::from((failed ^ Checked::new(U128::ONE)).0.is_none()));
+        assert!(bool::from((Checked::new(U128::ONE) ^ failed).0.is_none()));
+    }
 }
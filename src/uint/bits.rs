@@ -1,4 +1,4 @@
-use crate::{CtChoice, Limb, Uint, Word};
+use crate::{CtChoice, Limb, Uint, WideWord, Word};
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Returns `true` if the bit at position `index` is set, `false` otherwise.
@@ -168,8 +168,21 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         CtChoice::from_lsb(result >> index_in_limb)
     }
 
+    /// Calculate the number of ones in the binary representation of this number.
+    pub const fn count_ones(&self) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            count += self.limbs[i].count_ones();
+            i += 1;
+        }
+
+        count
+    }
+
     /// Sets the bit at `index` to 0 or 1 depending on the value of `bit_value`.
-    pub(crate) const fn set_bit(self, index: usize, bit_value: CtChoice) -> Self {
+    /// Indices out of range are ignored.
+    pub const fn set_bit(self, index: usize, bit_value: CtChoice) -> Self {
         let mut result = self;
         let limb_num = index / Limb::BITS;
         let index_in_limb = index % Limb::BITS;
@@ -185,11 +198,223 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         }
         result
     }
+
+    /// Sets the bit at `index` to 0 or 1 depending on the value of `bit_value`.
+    /// Indices out of range are ignored.
+    ///
+    /// # Remarks
+    /// This operation is variable time with respect to `index` and `bit_value`.
+    pub const fn set_bit_vartime(self, index: usize, bit_value: bool) -> Self {
+        let mut result = self;
+        if index < Self::BITS {
+            let limb_num = index / Limb::BITS;
+            let index_mask = 1 << (index % Limb::BITS);
+            if bit_value {
+                result.limbs[limb_num].0 |= index_mask;
+            } else {
+                result.limbs[limb_num].0 &= !index_mask;
+            }
+        }
+        result
+    }
+
+    /// Clears the bit at `index`.
+    /// Indices out of range are ignored.
+    pub const fn clear_bit(self, index: usize) -> Self {
+        self.set_bit(index, CtChoice::FALSE)
+    }
+
+    /// Clears the bit at `index`.
+    /// Indices out of range are ignored.
+    ///
+    /// # Remarks
+    /// This operation is variable time with respect to `index`.
+    pub const fn clear_bit_vartime(self, index: usize) -> Self {
+        self.set_bit_vartime(index, false)
+    }
+
+    /// Returns a mask with the `k` least significant bits set.
+    /// Returns [`Uint::MAX`] if `k >= Self::BITS`.
+    pub const fn low_mask(k: usize) -> Self {
+        let mut limbs = [Limb::ZERO; LIMBS];
+        let mut i = 0;
+        while i < LIMBS {
+            let lo = i * Limb::BITS;
+            let is_full = CtChoice::from_usize_lt(lo + Limb::BITS - 1, k);
+            let is_partial = CtChoice::from_usize_lt(lo, k);
+            let partial_mask = (1 << (k.wrapping_sub(lo) % Limb::BITS)) - 1;
+            limbs[i] = Limb(is_full.select(is_partial.if_true(partial_mask), Word::MAX));
+            i += 1;
+        }
+
+        Self { limbs }
+    }
+
+    /// Returns a mask with the `k` least significant bits set.
+    /// Returns [`Uint::MAX`] if `k >= Self::BITS`.
+    ///
+    /// # Remarks
+    /// This operation is variable time with respect to `k`.
+    pub const fn low_mask_vartime(k: usize) -> Self {
+        let mut limbs = [Limb::ZERO; LIMBS];
+        let mut i = 0;
+        while i < LIMBS && (i + 1) * Limb::BITS <= k {
+            limbs[i] = Limb::MAX;
+            i += 1;
+        }
+        if i < LIMBS {
+            limbs[i] = Limb((1 << (k - i * Limb::BITS)) - 1);
+        }
+
+        Self { limbs }
+    }
+
+    /// Returns the `len` bits of `self` starting at bit position `pos`,
+    /// in the least significant bits of a [`Word`].
+    /// Bits beyond [`Uint::BITS`] are read as zero.
+    ///
+    /// Panics if `len > Limb::BITS`.
+    ///
+    /// # Remarks
+    /// This operation is variable time with respect to `len` only.
+    pub const fn extract_bits(&self, pos: usize, len: usize) -> Word {
+        assert!(len <= Limb::BITS);
+
+        let limb_num = pos / Limb::BITS;
+        let mut lo: Word = 0;
+        let mut hi: Word = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            lo |= CtChoice::from_usize_equality(i, limb_num).if_true(self.limbs[i].0);
+            hi |= CtChoice::from_usize_equality(i, limb_num + 1).if_true(self.limbs[i].0);
+            i += 1;
+        }
+
+        let window = ((hi as WideWord) << Limb::BITS) | (lo as WideWord);
+        ((window >> (pos % Limb::BITS)) as Word) & Self::word_mask(len)
+    }
+
+    /// Returns the `len` bits of `self` starting at bit position `pos`,
+    /// in the least significant bits of a [`Word`].
+    /// Bits beyond [`Uint::BITS`] are read as zero.
+    ///
+    /// Panics if `len > Limb::BITS`.
+    ///
+    /// # Remarks
+    /// This operation is variable time with respect to `pos` and `len`.
+    pub const fn extract_bits_vartime(&self, pos: usize, len: usize) -> Word {
+        assert!(len <= Limb::BITS);
+
+        let limb_num = pos / Limb::BITS;
+        let lo = if limb_num < LIMBS {
+            self.limbs[limb_num].0
+        } else {
+            0
+        };
+        let hi = if limb_num + 1 < LIMBS {
+            self.limbs[limb_num + 1].0
+        } else {
+            0
+        };
+
+        let window = ((hi as WideWord) << Limb::BITS) | (lo as WideWord);
+        ((window >> (pos % Limb::BITS)) as Word) & Self::word_mask(len)
+    }
+
+    /// Replaces the `len` bits of `self` starting at bit position `pos`
+    /// with the `len` least significant bits of `value`.
+    /// Bits beyond [`Uint::BITS`] are discarded.
+    ///
+    /// Panics if `len > Limb::BITS`.
+    ///
+    /// # Remarks
+    /// This operation is variable time with respect to `len` only.
+    pub const fn insert_bits(&self, pos: usize, len: usize, value: Word) -> Self {
+        assert!(len <= Limb::BITS);
+
+        let limb_num = pos / Limb::BITS;
+        let mask = Self::word_mask(len);
+        let wide_mask = (mask as WideWord) << (pos % Limb::BITS);
+        let wide_value = ((value & mask) as WideWord) << (pos % Limb::BITS);
+
+        let mut limbs = self.limbs;
+        let mut i = 0;
+        while i < LIMBS {
+            let is_lo = CtChoice::from_usize_equality(i, limb_num);
+            let is_hi = CtChoice::from_usize_equality(i, limb_num + 1);
+            let m =
+                is_lo.if_true(wide_mask as Word) | is_hi.if_true((wide_mask >> Limb::BITS) as Word);
+            let v = is_lo.if_true(wide_value as Word)
+                | is_hi.if_true((wide_value >> Limb::BITS) as Word);
+            limbs[i] = Limb((limbs[i].0 & !m) | v);
+            i += 1;
+        }
+
+        Self { limbs }
+    }
+
+    /// Replaces the `len` bits of `self` starting at bit position `pos`
+    /// with the `len` least significant bits of `value`.
+    /// Bits beyond [`Uint::BITS`] are discarded.
+    ///
+    /// Panics if `len > Limb::BITS`.
+    ///
+    /// # Remarks
+    /// This operation is variable time with respect to `pos` and `len`.
+    pub const fn insert_bits_vartime(&self, pos: usize, len: usize, value: Word) -> Self {
+        assert!(len <= Limb::BITS);
+
+        let limb_num = pos / Limb::BITS;
+        let mask = Self::word_mask(len);
+        let wide_mask = (mask as WideWord) << (pos % Limb::BITS);
+        let wide_value = ((value & mask) as WideWord) << (pos % Limb::BITS);
+
+        let mut limbs = self.limbs;
+        if limb_num < LIMBS {
+            limbs[limb_num].0 = (limbs[limb_num].0 & !(wide_mask as Word)) | wide_value as Word;
+        }
+        if limb_num + 1 < LIMBS {
+            limbs[limb_num + 1].0 = (limbs[limb_num + 1].0 & !((wide_mask >> Limb::BITS) as Word))
+                | (wide_value >> Limb::BITS) as Word;
+        }
+
+        Self { limbs }
+    }
+
+    /// Reverses the order of bits in `self`.
+    pub const fn reverse_bits(&self) -> Self {
+        let mut limbs = [Limb::ZERO; LIMBS];
+        let mut i = 0;
+        while i < LIMBS {
+            limbs[i] = Limb(self.limbs[LIMBS - 1 - i].0.reverse_bits());
+            i += 1;
+        }
+
+        Self { limbs }
+    }
+
+    /// Reverses the byte order of `self`.
+    pub const fn swap_bytes(&self) -> Self {
+        let mut limbs = [Limb::ZERO; LIMBS];
+        let mut i = 0;
+        while i < LIMBS {
+            limbs[i] = Limb(self.limbs[LIMBS - 1 - i].0.swap_bytes());
+            i += 1;
+        }
+
+        Self { limbs }
+    }
+
+    /// Returns a [`Word`] with the `len` least significant bits set, where `len <= Limb::BITS`.
+    const fn word_mask(len: usize) -> Word {
+        let one: WideWord = 1;
+        ((one << len) - 1) as Word
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{CtChoice, U256};
+    use crate::{CtChoice, Limb, Word, U256};
 
     fn uint_with_bits_at(positions: &[usize]) -> U256 {
         let mut result = U256::ZERO;
@@ -357,4 +582,109 @@ mod tests {
             uint_with_bits_at(&[16, 79])
         );
     }
+
+    #[test]
+    fn set_bit_vartime() {
+        let u = uint_with_bits_at(&[16, 79, 150]);
+        assert_eq!(
+            u.set_bit_vartime(127, true),
+            uint_with_bits_at(&[16, 79, 127, 150])
+        );
+        assert_eq!(u.set_bit_vartime(150, false), uint_with_bits_at(&[16, 79]));
+        assert_eq!(u.set_bit_vartime(256, true), u);
+    }
+
+    #[test]
+    fn clear_bit() {
+        let u = uint_with_bits_at(&[16, 79, 150]);
+        assert_eq!(u.clear_bit(79), uint_with_bits_at(&[16, 150]));
+        assert_eq!(u.clear_bit(80), u);
+        assert_eq!(u.clear_bit(256), u);
+        assert_eq!(u.clear_bit_vartime(79), uint_with_bits_at(&[16, 150]));
+        assert_eq!(u.clear_bit_vartime(256), u);
+    }
+
+    #[test]
+    fn count_ones() {
+        assert_eq!(U256::ZERO.count_ones(), 0);
+        assert_eq!(U256::MAX.count_ones(), 256);
+        assert_eq!(uint_with_bits_at(&[16, 79, 150, 255]).count_ones(), 4);
+    }
+
+    #[test]
+    fn low_mask() {
+        for k in 0..=U256::BITS + 1 {
+            let expected = if k >= U256::BITS {
+                U256::MAX
+            } else {
+                (U256::ONE << k).wrapping_sub(&U256::ONE)
+            };
+            assert_eq!(U256::low_mask(k), expected);
+            assert_eq!(U256::low_mask_vartime(k), expected);
+        }
+        assert_eq!(U256::low_mask(usize::MAX), U256::MAX);
+    }
+
+    #[test]
+    fn extract_bits() {
+        let u = uint_with_bits_at(&[16, 63, 64, 130, 255]);
+        for (pos, len, expected) in [
+            (16, 1, 1),
+            (16, 0, 0),
+            (15, 3, 0b010),
+            (62, 4, 0b0110),
+            (128, 8, 0b100),
+            (250, 16, 0b100000),
+            (256, 8, 0),
+            (1000, 8, 0),
+        ] {
+            assert_eq!(u.extract_bits(pos, len), expected);
+            assert_eq!(u.extract_bits_vartime(pos, len), expected);
+        }
+        assert_eq!(U256::MAX.extract_bits(3, Limb::BITS), Word::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn extract_bits_too_long() {
+        U256::ZERO.extract_bits(0, Limb::BITS + 1);
+    }
+
+    #[test]
+    fn insert_bits() {
+        let u = uint_with_bits_at(&[16, 63, 64, 130, 255]);
+        for (pos, len, value, expected) in [
+            (16, 1, 0, uint_with_bits_at(&[63, 64, 130, 255])),
+            (62, 4, 0b1001, uint_with_bits_at(&[16, 62, 65, 130, 255])),
+            (120, 16, 0, uint_with_bits_at(&[16, 63, 64, 255])),
+            (
+                254,
+                4,
+                0b1111,
+                uint_with_bits_at(&[16, 63, 64, 130, 254, 255]),
+            ),
+            (300, 4, 0b1111, u),
+            (16, 0, 0b1111, u),
+        ] {
+            assert_eq!(u.insert_bits(pos, len, value), expected);
+            assert_eq!(u.insert_bits_vartime(pos, len, value), expected);
+        }
+    }
+
+    #[test]
+    fn reverse_bits() {
+        let u = uint_with_bits_at(&[0, 16, 130]);
+        assert_eq!(u.reverse_bits(), uint_with_bits_at(&[255, 239, 125]));
+        assert_eq!(u.reverse_bits().reverse_bits(), u);
+    }
+
+    #[test]
+    fn swap_bytes() {
+        let u =
+            U256::from_be_hex("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+        assert_eq!(
+            u.swap_bytes(),
+            U256::from_be_hex("1f1e1d1c1b1a191817161514131211100f0e0d0c0b0a09080706050403020100")
+        );
+    }
 }
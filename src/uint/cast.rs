@@ -0,0 +1,122 @@
+//! Zero-copy conversions between [`Uint`] and plain old data, via [`bytemuck`].
+
+use crate::{Limb, Uint, Word};
+use bytemuck::{Pod, PodCastError, Zeroable};
+
+// SAFETY: `Uint` is a `repr(transparent)` wrapper around `[Limb; LIMBS]`, and `Limb` is `Pod`
+#[allow(unsafe_code)]
+unsafe impl<const LIMBS: usize> Zeroable for Uint<LIMBS> {}
+
+// SAFETY: `Uint` is a `repr(transparent)` wrapper around `[Limb; LIMBS]`, and `Limb` is `Pod`
+#[allow(unsafe_code)]
+unsafe impl<const LIMBS: usize> Pod for Uint<LIMBS> {}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Reinterpret a slice of [`Word`]s as a slice of [`Uint`]s, each made of `LIMBS`
+    /// consecutive words in least significant first order.
+    ///
+    /// Returns an error if the length of `words` is not a multiple of `LIMBS`.
+    pub fn cast_slice_from_words(words: &[Word]) -> Result<&[Self], PodCastError> {
+        bytemuck::try_cast_slice(words)
+    }
+
+    /// Reinterpret a mutable slice of [`Word`]s as a mutable slice of [`Uint`]s, each made of
+    /// `LIMBS` consecutive words in least significant first order.
+    ///
+    /// Returns an error if the length of `words` is not a multiple of `LIMBS`.
+    pub fn cast_slice_from_words_mut(words: &mut [Word]) -> Result<&mut [Self], PodCastError> {
+        bytemuck::try_cast_slice_mut(words)
+    }
+
+    /// Reinterpret a slice of [`Uint`]s as a slice of their [`Word`]s.
+    pub fn cast_slice_to_words(uints: &[Self]) -> &[Word] {
+        bytemuck::cast_slice(uints)
+    }
+
+    /// Reinterpret a slice of bytes as a slice of [`Uint`]s, each encoded as
+    /// [`Uint::BYTES`] consecutive little endian bytes.
+    ///
+    /// Only available on little endian targets, where this matches the in-memory layout.
+    ///
+    /// Returns an error if `bytes` is not aligned to a [`Word`], or if its length is not a
+    /// multiple of [`Uint::BYTES`].
+    #[cfg(target_endian = "little")]
+    pub fn cast_slice_from_le_bytes(bytes: &[u8]) -> Result<&[Self], PodCastError> {
+        bytemuck::try_cast_slice(bytes)
+    }
+
+    /// Reinterpret a slice of [`Uint`]s as a slice of bytes, each [`Uint`] encoded as
+    /// [`Uint::BYTES`] consecutive little endian bytes.
+    ///
+    /// Only available on little endian targets, where this matches the in-memory layout.
+    #[cfg(target_endian = "little")]
+    pub fn cast_slice_to_le_bytes(uints: &[Self]) -> &[u8] {
+        bytemuck::cast_slice(uints)
+    }
+}
+
+impl Limb {
+    /// Reinterpret a slice of [`Word`]s as a slice of [`Limb`]s.
+    pub fn cast_slice_from_words(words: &[Word]) -> &[Self] {
+        bytemuck::cast_slice(words)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{Limb, Word, U128, U64};
+    use bytemuck::PodCastError;
+
+    #[test]
+    fn words_round_trip() {
+        let words: [Word; 4] = [1, 2, 3, 4];
+        let uints = U128::cast_slice_from_words(&words).unwrap();
+        assert_eq!(uints.len(), words.len() / U128::LIMBS);
+        assert_eq!(uints[0].as_words(), &words[..U128::LIMBS]);
+        assert_eq!(U128::cast_slice_to_words(uints), &words);
+
+        let limbs = Limb::cast_slice_from_words(&words);
+        assert_eq!(limbs[3], Limb(4));
+    }
+
+    #[test]
+    fn words_mut() {
+        let mut words: [Word; 4] = [0; 4];
+        let uints = U64::cast_slice_from_words_mut(&mut words).unwrap();
+        uints[0] = U64::MAX;
+        assert_eq!(words[0], Word::MAX);
+    }
+
+    #[test]
+    fn words_size_mismatch() {
+        let words: [Word; 3] = [0; 3];
+        assert_eq!(
+            U128::cast_slice_from_words(&words[..U128::LIMBS + 1]).unwrap_err(),
+            PodCastError::OutputSliceWouldHaveSlop
+        );
+    }
+
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn le_bytes_round_trip() {
+        let mut words = [0; 2 * U128::LIMBS];
+        words[0] = 0x0102;
+        words[U128::LIMBS] = Word::MAX;
+        let bytes = U128::cast_slice_to_le_bytes(U128::cast_slice_from_words(&words).unwrap());
+        assert_eq!(bytes.len(), 2 * U128::BYTES);
+
+        let uints = U128::cast_slice_from_le_bytes(bytes).unwrap();
+        assert_eq!(uints[0], U128::from_le_slice(&bytes[..U128::BYTES]));
+        assert_eq!(uints[1], U128::from_le_slice(&bytes[U128::BYTES..]));
+
+        assert_eq!(
+            U128::cast_slice_from_le_bytes(&bytes[..U128::BYTES - 1]).unwrap_err(),
+            PodCastError::OutputSliceWouldHaveSlop
+        );
+        assert_eq!(
+            U128::cast_slice_from_le_bytes(&bytes[1..U128::BYTES + 1]).unwrap_err(),
+            PodCastError::TargetAlignmentGreaterAndInputNotAligned
+        );
+    }
+}
@@ -1,7 +1,7 @@
 //! [`Uint`] division operations.
 
-use super::div_limb::{div_rem_limb_with_reciprocal, Reciprocal};
-use crate::{CtChoice, Limb, NonZero, Uint, Word, Wrapping};
+use super::div_limb::{div3by2, div_rem_limb_with_reciprocal, Reciprocal};
+use crate::{Checked, CtChoice, Limb, NonZero, Uint, Word, Wrapping};
 use core::ops::{Div, DivAssign, Rem, RemAssign};
 use subtle::CtOption;
 
@@ -112,32 +112,101 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     /// When used with a fixed `rhs`, this function is constant-time with respect
     /// to `self`.
     pub const fn const_rem_wide(lower_upper: (Self, Self), rhs: &Self) -> (Self, CtChoice) {
-        let mb = rhs.bits_vartime();
+        let yc = (rhs.bits_vartime() + Limb::BITS - 1) / Limb::BITS;
 
-        // The number of bits to consider is two sets of limbs * BITS - mb (modulus bitcount)
-        let mut bd = (2 * Self::BITS) - mb;
+        if yc == 0 {
+            return (lower_upper.0, CtChoice::FALSE);
+        }
 
-        // The wide integer to reduce, split into two halves
-        let (mut lower, mut upper) = lower_upper;
+        let shift = rhs.leading_zeros_vartime() % Limb::BITS;
+        let (lower, upper) = Self::shl_vartime_wide(lower_upper, shift);
+        let (_, x_hi) = lower_upper.1.shl_limb(shift);
+        let (y, _) = rhs.shl_limb(shift);
+        let (_, rem) = div_rem_knuth([lower.limbs, upper.limbs], x_hi, 2 * LIMBS, &y.limbs, yc);
 
-        // Factor of the modulus, split into two halves
-        let mut c = Self::shl_vartime_wide((*rhs, Uint::ZERO), bd);
+        (Self::new(rem).shr_vartime(shift), CtChoice::TRUE)
+    }
 
-        loop {
-            let (lower_sub, borrow) = lower.sbb(&c.0, Limb::ZERO);
-            let (upper_sub, borrow) = upper.sbb(&c.1, borrow);
+    /// Computes `self` / `rhs`, returns the quotient (q) and remainder (r).
+    ///
+    /// Uses Knuth's long division algorithm operating on whole limbs, which is much faster
+    /// than the bit-by-bit [`Self::div_rem`] for divisors spanning several limbs.
+    ///
+    /// This is variable-time only with respect to `rhs`.
+    ///
+    /// When used with a fixed `rhs`, this function is constant-time with respect
+    /// to `self`.
+    pub const fn div_rem_vartime(&self, rhs: &NonZero<Self>) -> (Self, Self) {
+        self.div_rem_mixed(rhs)
+    }
 
-            lower = Self::ct_select(&lower_sub, &lower, CtChoice::from_mask(borrow.0));
-            upper = Self::ct_select(&upper_sub, &upper, CtChoice::from_mask(borrow.0));
-            if bd == 0 {
-                break;
-            }
-            bd -= 1;
-            c = Self::shr_vartime_wide(c, 1);
+    /// Computes `self` % `rhs`, returns the remainder.
+    ///
+    /// See [`Self::div_rem_vartime`] for the details.
+    ///
+    /// This is variable-time only with respect to `rhs`.
+    ///
+    /// When used with a fixed `rhs`, this function is constant-time with respect
+    /// to `self`.
+    pub const fn rem_vartime(&self, rhs: &NonZero<Self>) -> Self {
+        self.div_rem_vartime(rhs).1
+    }
+
+    /// Computes `self` / `rhs` for a divisor of a different width,
+    /// returns the quotient (q) and remainder (r).
+    ///
+    /// See [`Self::div_rem_vartime`] for the details.
+    ///
+    /// This is variable-time only with respect to `rhs`.
+    ///
+    /// When used with a fixed `rhs`, this function is constant-time with respect
+    /// to `self`.
+    pub const fn div_rem_mixed<const RHS_LIMBS: usize>(
+        &self,
+        rhs: &NonZero<Uint<RHS_LIMBS>>,
+    ) -> (Self, Uint<RHS_LIMBS>) {
+        let yc = (rhs.as_uint().bits_vartime() + Limb::BITS - 1) / Limb::BITS;
+
+        // The divisor is wider than the dividend, hence greater than it
+        if yc > LIMBS {
+            return (Self::ZERO, self.resize());
         }
 
-        let is_some = Limb(mb as Word).ct_is_nonzero();
-        (lower, is_some)
+        if yc == 1 {
+            let (reciprocal, _) = Reciprocal::ct_new(rhs.as_uint().limbs[0]);
+            let (quo, rem) = div_rem_limb_with_reciprocal(self, &reciprocal);
+            return (quo, Uint::from_word(rem.0));
+        }
+
+        let shift = rhs.as_uint().leading_zeros_vartime() % Limb::BITS;
+        let (x, x_hi) = self.shl_limb(shift);
+        let (y, _) = rhs.as_uint().shl_limb(shift);
+        let (x, rem) = div_rem_knuth([x.limbs, [Limb::ZERO; LIMBS]], x_hi, LIMBS, &y.limbs, yc);
+
+        // The quotient occupies the limbs `yc - 1..LIMBS` of the processed dividend
+        let mut quo = [Limb::ZERO; LIMBS];
+        let mut i = 0;
+        while i <= LIMBS - yc {
+            quo[i] = x[0][i + yc - 1];
+            i += 1;
+        }
+
+        (Self::new(quo), Uint::new(rem).shr_vartime(shift))
+    }
+
+    /// Computes `self` % `rhs` for a divisor of a different width, returns the remainder.
+    ///
+    /// See [`Self::div_rem_vartime`] for the details.
+    ///
+    /// This is variable-time only with respect to `rhs`.
+    ///
+    /// When used with a fixed `rhs`, this function is constant-time with respect
+    /// to `self`.
+    pub const fn rem_mixed<const RHS_LIMBS: usize>(
+        &self,
+        rhs: &NonZero<Uint<RHS_LIMBS>>,
+    ) -> Uint<RHS_LIMBS> {
+        self.div_rem_mixed(rhs).1
     }
 
     /// Computes `self` % 2^k. Faster than reduce since its a power of 2.
@@ -217,6 +286,95 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 }
 
+/// Divides the normalized dividend made of the first `xc` limbs of `x` (where `x[0]` holds the
+/// least significant limbs and `x[1]` the most significant ones) and an extra top limb `x_hi`
+/// by the normalized divisor `y` with `yc` nonzero limbs, using Algorithm D from
+/// Knuth's TAOCP vol. 2, §4.3.1.
+///
+/// The divisor must have the most significant bit of its top limb set, and `x_hi` must be less
+/// than that limb. Returns the processed dividend, which contains the quotient in its limbs
+/// `yc - 1..xc`, and the (still normalized) remainder.
+///
+/// This is variable-time only with respect to `xc`, `yc` and `y`.
+const fn div_rem_knuth<const LIMBS: usize, const RHS_LIMBS: usize>(
+    mut x: [[Limb; LIMBS]; 2],
+    mut x_hi: Limb,
+    xc: usize,
+    y: &[Limb; RHS_LIMBS],
+    yc: usize,
+) -> ([[Limb; LIMBS]; 2], [Limb; RHS_LIMBS]) {
+    debug_assert!(yc > 0 && yc <= RHS_LIMBS && yc <= xc && xc <= 2 * LIMBS);
+
+    let (reciprocal, _) = Reciprocal::ct_new(y[yc - 1]);
+    let y_next = if yc > 1 { y[yc - 2].0 } else { 0 };
+
+    let mut xi = xc - 1;
+    loop {
+        // Estimate the quotient limb from the three top limbs of the current window
+        let x_next = if xi > 0 {
+            x[(xi - 1) / LIMBS][(xi - 1) % LIMBS].0
+        } else {
+            0
+        };
+        let mut quo = div3by2(
+            x_hi.0,
+            x[xi / LIMBS][xi % LIMBS].0,
+            x_next,
+            &reciprocal,
+            y_next,
+        );
+
+        // Subtract `quo * y` from the window
+        let mut carry = Limb::ZERO;
+        let mut borrow = Limb::ZERO;
+        let mut i = 0;
+        while i < yc {
+            let k = xi + 1 - yc + i;
+            let (prod, c) = Limb::ZERO.mac(y[i], Limb(quo), carry);
+            let (diff, b) = x[k / LIMBS][k % LIMBS].sbb(prod, borrow);
+            x[k / LIMBS][k % LIMBS] = diff;
+            carry = c;
+            borrow = b;
+            i += 1;
+        }
+        let (_, borrow) = x_hi.sbb(carry, borrow);
+
+        // If the estimate was one too large, add the divisor back and decrement the quotient limb.
+        // This is rarely needed, but has to be performed unconditionally.
+        let borrow = CtChoice::from_mask(borrow.0);
+        let mut carry = Limb::ZERO;
+        let mut i = 0;
+        while i < yc {
+            let k = xi + 1 - yc + i;
+            let addend = Limb::ct_select(Limb::ZERO, y[i], borrow);
+            let (sum, c) = x[k / LIMBS][k % LIMBS].adc(addend, carry);
+            x[k / LIMBS][k % LIMBS] = sum;
+            carry = c;
+            i += 1;
+        }
+        quo = borrow.select(quo, quo.wrapping_sub(1));
+
+        // The top limb of the window is now the top limb of the partial remainder
+        x_hi = x[xi / LIMBS][xi % LIMBS];
+        x[xi / LIMBS][xi % LIMBS] = Limb(quo);
+
+        if xi == yc - 1 {
+            break;
+        }
+        xi -= 1;
+    }
+
+    let mut rem = [Limb::ZERO; RHS_LIMBS];
+    let mut i = 0;
+    while i < yc - 1 {
+        rem[i] = x[i / LIMBS][i % LIMBS];
+        i += 1;
+    }
+    rem[yc - 1] = x_hi;
+
+    (x, rem)
+}
+
 //
 // Division by a single limb
 //
@@ -580,10 +738,162 @@ impl<const LIMBS: usize> RemAssign<&NonZero<Uint<LIMBS>>> for Wrapping<Uint<LIMB
     }
 }
 
+impl<const LIMBS: usize> Div<NonZero<Wrapping<Uint<LIMBS>>>> for Wrapping<Uint<LIMBS>> {
+    type Output = Wrapping<Uint<LIMBS>>;
+
+    fn div(self, rhs: NonZero<Wrapping<Uint<LIMBS>>>) -> Self::Output {
+        Wrapping(self.0 / NonZero::<Uint<LIMBS>>::const_new(rhs.as_ref().0).0)
+    }
+}
+
+impl<const LIMBS: usize> Div<&NonZero<Wrapping<Uint<LIMBS>>>> for Wrapping<Uint<LIMBS>> {
+    type Output = Wrapping<Uint<LIMBS>>;
+
+    fn div(self, rhs: &NonZero<Wrapping<Uint<LIMBS>>>) -> Self::Output {
+        self / *rhs
+    }
+}
+
+impl<const LIMBS: usize> DivAssign<NonZero<Wrapping<Uint<LIMBS>>>> for Wrapping<Uint<LIMBS>> {
+    fn div_assign(&mut self, rhs: NonZero<Wrapping<Uint<LIMBS>>>) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const LIMBS: usize> Div<NonZero<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    type Output = Checked<Uint<LIMBS>>;
+
+    fn div(self, rhs: NonZero<Uint<LIMBS>>) -> Self::Output {
+        Checked(self.0.map(|lhs| lhs / rhs))
+    }
+}
+
+impl<const LIMBS: usize> Div<&NonZero<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    type Output = Checked<Uint<LIMBS>>;
+
+    fn div(self, rhs: &NonZero<Uint<LIMBS>>) -> Self::Output {
+        self / *rhs
+    }
+}
+
+impl<const LIMBS: usize> DivAssign<NonZero<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    fn div_assign(&mut self, rhs: NonZero<Uint<LIMBS>>) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const LIMBS: usize> Div<Checked<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    type Output = Checked<Uint<LIMBS>>;
+
+    /// Fails if either operand is a failed computation, or if `rhs` is zero.
+    fn div(self, rhs: Checked<Uint<LIMBS>>) -> Self::Output {
+        Checked(
+            self.0
+                .and_then(|lhs| rhs.0.and_then(|rhs| lhs.checked_div(&rhs))),
+        )
+    }
+}
+
+impl<const LIMBS: usize> Div<&Checked<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    type Output = Checked<Uint<LIMBS>>;
+
+    fn div(self, rhs: &Checked<Uint<LIMBS>>) -> Self::Output {
+        self / *rhs
+    }
+}
+
+impl<const LIMBS: usize> DivAssign<Checked<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    fn div_assign(&mut self, rhs: Checked<Uint<LIMBS>>) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const LIMBS: usize> DivAssign<&Checked<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    fn div_assign(&mut self, rhs: &Checked<Uint<LIMBS>>) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const LIMBS: usize> Rem<NonZero<Wrapping<Uint<LIMBS>>>> for Wrapping<Uint<LIMBS>> {
+    type Output = Wrapping<Uint<LIMBS>>;
+
+    fn rem(self, rhs: NonZero<Wrapping<Uint<LIMBS>>>) -> Self::Output {
+        Wrapping(self.0 % NonZero::<Uint<LIMBS>>::const_new(rhs.as_ref().0).0)
+    }
+}
+
+impl<const LIMBS: usize> Rem<&NonZero<Wrapping<Uint<LIMBS>>>> for Wrapping<Uint<LIMBS>> {
+    type Output = Wrapping<Uint<LIMBS>>;
+
+    fn rem(self, rhs: &NonZero<Wrapping<Uint<LIMBS>>>) -> Self::Output {
+        self % *rhs
+    }
+}
+
+impl<const LIMBS: usize> RemAssign<NonZero<Wrapping<Uint<LIMBS>>>> for Wrapping<Uint<LIMBS>> {
+    fn rem_assign(&mut self, rhs: NonZero<Wrapping<Uint<LIMBS>>>) {
+        *self = *self % rhs;
+    }
+}
+
+impl<const LIMBS: usize> Rem<NonZero<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    type Output = Checked<Uint<LIMBS>>;
+
+    fn rem(self, rhs: NonZero<Uint<LIMBS>>) -> Self::Output {
+        Checked(self.0.map(|lhs| lhs % rhs))
+    }
+}
+
+impl<const LIMBS: usize> Rem<&NonZero<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    type Output = Checked<Uint<LIMBS>>;
+
+    fn rem(self, rhs: &NonZero<Uint<LIMBS>>) -> Self::Output {
+        self % *rhs
+    }
+}
+
+impl<const LIMBS: usize> RemAssign<NonZero<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    fn rem_assign(&mut self, rhs: NonZero<Uint<LIMBS>>) {
+        *self = *self % rhs;
+    }
+}
+
+impl<const LIMBS: usize> Rem<Checked<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    type Output = Checked<Uint<LIMBS>>;
+
+    /// Fails if either operand is a failed computation, or if `rhs` is zero.
+    fn rem(self, rhs: Checked<Uint<LIMBS>>) -> Self::Output {
+        Checked(
+            self.0
+                .and_then(|lhs| rhs.0.and_then(|rhs| lhs.checked_rem(&rhs))),
+        )
+    }
+}
+
+impl<const LIMBS: usize> Rem<&Checked<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    type Output = Checked<Uint<LIMBS>>;
+
+    fn rem(self, rhs: &Checked<Uint<LIMBS>>) -> Self::Output {
+        self % *rhs
+    }
+}
+
+impl<const LIMBS: usize> RemAssign<Checked<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    fn rem_assign(&mut self, rhs: Checked<Uint<LIMBS>>) {
+        *self = *self % rhs;
+    }
+}
+
+impl<const LIMBS: usize> RemAssign<&Checked<Uint<LIMBS>>> for Checked<Uint<LIMBS>> {
+    fn rem_assign(&mut self, rhs: &Checked<Uint<LIMBS>>) {
+        *self = *self % rhs;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{limb::HI_BIT, Limb, U256};
+    use crate::{limb::HI_BIT, Limb, U256, U512};
 
     #[cfg(feature = "rand")]
     use {
@@ -632,6 +942,62 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "rand")]
+    #[test]
+    fn div_rem_vartime() {
+        let mut rng = ChaChaRng::from_seed([7u8; 32]);
+        for _ in 0..25 {
+            let num = U256::random(&mut rng);
+            let den_bits = (rng.next_u32() % 256) as usize;
+            let den = U256::random(&mut rng).shr_vartime(den_bits) | U256::ONE;
+            let (q, r) = num.div_rem(&NonZero::new(den).unwrap());
+            assert_eq!(num.div_rem_vartime(&NonZero::new(den).unwrap()), (q, r));
+            assert_eq!(num.rem_vartime(&NonZero::new(den).unwrap()), r);
+        }
+    }
+
+    #[test]
+    fn div_rem_vartime_max() {
+        let den = NonZero::new(U256::MAX).unwrap();
+        assert_eq!(U256::MAX.div_rem_vartime(&den), (U256::ONE, U256::ZERO));
+        assert_eq!(U256::ONE.div_rem_vartime(&den), (U256::ZERO, U256::ONE));
+
+        // Top limbs of the dividend equal to the top limb of the divisor
+        let num =
+            U256::from_be_hex("8000000000000000000000000000000000000000000000000000000000000000");
+        let den =
+            U256::from_be_hex("0000000000000000800000000000000000000000000000000000000000000001");
+        let (q, r) = num.div_rem(&NonZero::new(den).unwrap());
+        assert_eq!(num.div_rem_vartime(&NonZero::new(den).unwrap()), (q, r));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn div_rem_mixed() {
+        let mut rng = ChaChaRng::from_seed([7u8; 32]);
+        for _ in 0..25 {
+            let num = U512::random(&mut rng);
+            for den_bits in [1, 63, 64, 65, 200, 256] {
+                let den = U256::random(&mut rng).shr_vartime(256 - den_bits) | U256::ONE;
+                let (q, r) = num.div_rem(&NonZero::new(den.resize()).unwrap());
+                let (q2, r2) = num.div_rem_mixed(&NonZero::new(den).unwrap());
+                assert_eq!(q, q2);
+                assert_eq!(r, r2.resize());
+                assert_eq!(num.rem_mixed(&NonZero::new(den).unwrap()), r2);
+            }
+        }
+    }
+
+    #[test]
+    fn div_rem_mixed_wider_rhs() {
+        let num = U256::MAX;
+        let den = NonZero::new(U512::ONE.shl_vartime(256)).unwrap();
+        assert_eq!(num.div_rem_mixed(&den), (U256::ZERO, U512::from(&num)));
+
+        let den = NonZero::new(U512::from(&num)).unwrap();
+        assert_eq!(num.div_rem_mixed(&den), (U256::ONE, U512::ZERO));
+    }
+
     #[test]
     fn div_max() {
         let mut a = U256::ZERO;
@@ -702,6 +1068,38 @@ mod tests {
         assert_eq!(r, U256::from(3u8));
     }
 
+    #[test]
+    fn reduce_tests_wide() {
+        let lower =
+            U256::from_be_hex("DF2E4D8B4E4C6B2F0E3E1FEDA2E8B5F7A91E6C8D3B4A5F6E7D8C9B0A1F2E3D4C");
+        let upper =
+            U256::from_be_hex("0000000000000000A5F3C1D2E4B6978812345678F0E1D2C3B4A5968778695A4B");
+        let modulus =
+            U256::from_be_hex("00000000FFFFFFFF000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF");
+
+        // Reference value computed with the bit-by-bit double-width remainder
+        let wide = upper.concat(&lower);
+        let expected = wide.rem(&NonZero::new(U256::ZERO.concat(&modulus)).unwrap());
+        let (hi, lo) = expected.split();
+        assert_eq!(hi, U256::ZERO);
+
+        let (r, is_some) = U256::const_rem_wide((lower, upper), &modulus);
+        assert!(is_some.is_true_vartime());
+        assert_eq!(r, lo);
+
+        let (r, is_some) = U256::const_rem_wide((lower, upper), &U256::from(7u8));
+        assert!(is_some.is_true_vartime());
+        let expected = wide.rem(&NonZero::new(U512::from(7u8)).unwrap());
+        assert_eq!(r, expected.split().1);
+    }
+
+    #[test]
+    fn reduce_tests_wide_zero() {
+        let (r, is_some) = U256::const_rem_wide((U256::from(10u8), U256::ONE), &U256::ZERO);
+        assert!(!is_some.is_true_vartime());
+        assert_eq!(r, U256::from(10u8));
+    }
+
     #[test]
     fn reduce_max() {
         let mut a = U256::ZERO;
@@ -742,4 +1140,32 @@ mod tests {
         assert_eq!(&a % b, c);
         assert_eq!(&a % &b, c);
     }
+
+    #[test]
+    fn div_rem_wrapping_non_zero() {
+        let x = Wrapping(U256::from(100u8));
+        let d = NonZero::new(Wrapping(U256::from(7u8))).unwrap();
+        assert_eq!(x / d, Wrapping(U256::from(14u8)));
+        assert_eq!(x % d, Wrapping(U256::from(2u8)));
+    }
+
+    #[test]
+    fn div_rem_checked() {
+        let x = Checked::new(U256::from(100u8));
+        let d = NonZero::new(U256::from(7u8)).unwrap();
+        assert_eq!((x / d).0.unwrap(), U256::from(14u8));
+        assert_eq!((x % d).0.unwrap(), U256::from(2u8));
+        assert_eq!((x / Checked::new(*d)).0.unwrap(), U256::from(14u8));
+        assert_eq!((x % Checked::new(*d)).0.unwrap(), U256::from(2u8));
+
+        let zero = Checked::new(U256::ZERO);
+        assert!(bool::from((x / zero).0.is_none()));
+        assert!(bool::from((x % zero).0.is_none()));
+
+        let failed = zero - Checked::new(U256::ONE);
+        assert!(bool::from((failed / d).0.is_none()));
+        assert!(bool::from((failed % d).0.is_none()));
+        assert!(bool::from((x / failed).0.is_none()));
+        assert!(bool::from((x % failed).0.is_none()));
+    }
 }
@@ -157,6 +157,48 @@ const fn div2by1(u1: Word, u0: Word, reciprocal: &Reciprocal) -> (Word, Word) {
     (q1, r)
 }
 
+/// Given the three most significant words of a dividend `u = (u2, u1, u0)` and the two most
+/// significant words of a divisor `v = (v1, v0)`, where `v1` is supplied via its reciprocal and
+/// `(u2, u1) <= (v1, v0)`, estimates the quotient word `q` so that `q - 1 <= floor(u / v) <= q`.
+///
+/// The divisor must be normalized, i.e. the reciprocal's `shift` must be 0
+/// (this corresponds to the step D3 of Algorithm D in TAOCP vol. 2, §4.3.1).
+#[inline(always)]
+pub(crate) const fn div3by2(
+    u2: Word,
+    u1: Word,
+    u0: Word,
+    v1_reciprocal: &Reciprocal,
+    v0: Word,
+) -> Word {
+    debug_assert!(v1_reciprocal.shift == 0);
+    debug_assert!(u2 <= v1_reciprocal.divisor_normalized);
+
+    // If the top word of the dividend equals the top word of the divisor,
+    // `div2by1()` cannot be used; the quotient estimate is capped at `Word::MAX` instead,
+    // and the remainder is `u2 * b + u1 - v1 * (b - 1) = u1 + v1`.
+    let q_maxed = Limb::ct_eq(Limb(u2), Limb(v1_reciprocal.divisor_normalized));
+    let (quo, rem) = div2by1(q_maxed.select(u2, 0), u1, v1_reciprocal);
+    let mut quo = q_maxed.select(quo, Word::MAX);
+    let mut rem = q_maxed.select_wide(rem as WideWord, (u1 as WideWord) + (u2 as WideWord));
+
+    // At most two corrections are needed.
+    let mut i = 0;
+    while i < 2 {
+        let qy = (quo as WideWord) * (v0 as WideWord);
+        let rx = (rem << Word::BITS) | (u0 as WideWord);
+        // If `rem < b` and `quo * v0 > rem * b + u0`, decrement `quo` and add `v1` to `rem`.
+        let done = Limb((rem >> Word::BITS) as Word)
+            .ct_is_nonzero()
+            .or(CtChoice::from_wide_word_le(qy, rx));
+        quo = done.select(quo.wrapping_sub(1), quo);
+        rem = done.select_wide(rem + (v1_reciprocal.divisor_normalized as WideWord), rem);
+        i += 1;
+    }
+
+    quo
+}
+
 /// A pre-calculated reciprocal for division by a single limb.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Reciprocal {
@@ -1,13 +1,57 @@
 //! Const-friendly decoding operations for [`Uint`]
 
+#[cfg(feature = "base64url")]
+mod base64url;
+
+#[cfg(feature = "borsh")]
+mod borsh;
+
 #[cfg(all(feature = "der", feature = "generic-array"))]
 mod der;
 
+#[cfg(feature = "openpgp")]
+mod openpgp;
+
 #[cfg(feature = "rlp")]
 mod rlp;
 
+#[cfg(feature = "parity-scale-codec")]
+mod scale;
+
+#[cfg(feature = "ssh")]
+mod ssh;
+
 use super::Uint;
-use crate::{Encoding, Limb, Word};
+use crate::{limb_slice, CtChoice, Encoding, Limb, Word};
+use core::fmt;
+use subtle::{ConstantTimeEq, CtOption};
+
+/// Error returned when decoding a [`Uint`] from a hex string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HexError {
+    /// The string contains no hex digits, or, for little endian strings,
+    /// an odd number of them.
+    InvalidLength,
+
+    /// The character at byte offset `position` is neither a hex digit nor an underscore.
+    InvalidDigit {
+        /// Byte offset of the invalid character in the string.
+        position: usize,
+    },
+
+    /// The value doesn't fit in the integer.
+    Overflow,
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength => f.write_str("invalid hex string length"),
+            Self::InvalidDigit { position } => write!(f, "invalid hex digit at {position}"),
+            Self::Overflow => f.write_str("hex string overflows the integer"),
+        }
+    }
+}
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Create a new [`Uint`] from the provided big endian bytes.
@@ -122,6 +166,162 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         Uint::new(res)
     }
 
+    /// Create a new [`Uint`] from the provided big endian hex string,
+    /// returning an error instead of panicking if it is malformed.
+    ///
+    /// The string may start with a `0x` prefix and contain underscores between digits.
+    /// Strings shorter than the integer are zero-extended, longer ones must only
+    /// have leading zeros in excess.
+    pub const fn try_from_be_hex(hex: &str) -> Result<Self, HexError> {
+        let bytes = hex.as_bytes();
+        let start = match validate_hex(bytes) {
+            Ok(start) => start,
+            Err(err) => return Err(err),
+        };
+
+        let mut res = [Limb::ZERO; LIMBS];
+        let mut overflow = 0;
+        let mut digits = 0;
+        let mut i = bytes.len();
+
+        // Least significant digits come last
+        while i > start {
+            i -= 1;
+            if bytes[i] == b'_' {
+                continue;
+            }
+
+            let nibble = decode_nibble(bytes[i]) as Word;
+            let offset = digits * 4;
+            if offset < Self::BITS {
+                res[offset / Limb::BITS].0 |= nibble << (offset % Limb::BITS);
+            } else {
+                overflow |= nibble;
+            }
+            digits += 1;
+        }
+
+        if digits == 0 {
+            Err(HexError::InvalidLength)
+        } else if overflow != 0 {
+            Err(HexError::Overflow)
+        } else {
+            Ok(Uint::new(res))
+        }
+    }
+
+    /// Create a new [`Uint`] from the provided little endian hex string,
+    /// returning an error instead of panicking if it is malformed.
+    ///
+    /// The string may start with a `0x` prefix and contain underscores between digits.
+    /// Each byte is given by two digits; strings shorter than the integer are
+    /// zero-extended, longer ones must only have trailing zero bytes in excess.
+    pub const fn try_from_le_hex(hex: &str) -> Result<Self, HexError> {
+        let bytes = hex.as_bytes();
+        let start = match validate_hex(bytes) {
+            Ok(start) => start,
+            Err(err) => return Err(err),
+        };
+
+        let mut res = [Limb::ZERO; LIMBS];
+        let mut overflow = 0;
+        let mut digits = 0;
+        let mut i = start;
+
+        while i < bytes.len() {
+            if bytes[i] == b'_' {
+                i += 1;
+                continue;
+            }
+
+            let nibble = decode_nibble(bytes[i]) as Word;
+            // The high nibble of each byte comes first
+            let offset = (digits / 2) * 8 + (1 - digits % 2) * 4;
+            if offset < Self::BITS {
+                res[offset / Limb::BITS].0 |= nibble << (offset % Limb::BITS);
+            } else {
+                overflow |= nibble;
+            }
+            digits += 1;
+            i += 1;
+        }
+
+        if digits == 0 || digits % 2 != 0 {
+            Err(HexError::InvalidLength)
+        } else if overflow != 0 {
+            Err(HexError::Overflow)
+        } else {
+            Ok(Uint::new(res))
+        }
+    }
+
+    /// Serialize this [`Uint`] as big-endian without leading zeros, writing it into the
+    /// beginning of `out` and returning the number of bytes written.
+    ///
+    /// Zero is encoded as an empty string. This is the integer encoding used by RLP,
+    /// and the basis of the SSH, OpenPGP and JWK ones.
+    ///
+    /// NOTE: the length of the encoding is leaked in the time pattern,
+    /// use [`Uint::to_be_bytes_padded`] when it must stay secret.
+    ///
+    /// # Panics
+    ///
+    /// If `out` is shorter than the encoding, which never happens if it is at least
+    /// [`Uint::BYTES`] long.
+    pub fn to_be_bytes_minimal(&self, out: &mut [u8]) -> usize {
+        limb_slice::to_be_bytes_minimal(&self.limbs, out)
+    }
+
+    /// Decode a [`Uint`] from big-endian bytes without leading zeros,
+    /// as produced by [`Uint::to_be_bytes_minimal`].
+    ///
+    /// Returns `None` if `bytes` starts with a zero byte or the value doesn't fit.
+    /// Runs in constant time with respect to the contents of `bytes`, but not its length.
+    pub fn from_be_bytes_minimal(bytes: &[u8]) -> CtOption<Self> {
+        let leading_zero = bytes.first().map_or(0.into(), |byte| byte.ct_eq(&0));
+        Self::from_be_bytes_padded(bytes).and_then(|uint| CtOption::new(uint, !leading_zero))
+    }
+
+    /// Serialize this [`Uint`] as big-endian, left-padded with zeros to the length of `out`,
+    /// in constant time.
+    ///
+    /// Returns a falsy [`CtChoice`] if the value doesn't fit in `out`,
+    /// in which case its most significant bytes are dropped.
+    pub fn to_be_bytes_padded(&self, out: &mut [u8]) -> CtChoice {
+        limb_slice::to_be_bytes_padded(&self.limbs, out)
+    }
+
+    /// Decode a [`Uint`] from big-endian bytes of any length, in constant time.
+    ///
+    /// Leading zeros are accepted. Returns `None` if the value doesn't fit.
+    pub fn from_be_bytes_padded(bytes: &[u8]) -> CtOption<Self> {
+        let mut limbs = [Limb::ZERO; LIMBS];
+        let fits = limb_slice::from_be_bytes_padded(&mut limbs, bytes);
+        CtOption::new(Uint::new(limbs), fits.into())
+    }
+
+    /// Serialize this [`Uint`] as a big endian lowercase hex string of `N` digits.
+    ///
+    /// `N` must be `Self::BITS / 4`: the `to_be_hex_array` methods of the type aliases
+    /// (e.g. [`U256::to_be_hex_array`][`crate::U256::to_be_hex_array`]) pass the right length.
+    pub(crate) const fn be_hex_array<const N: usize>(&self) -> [u8; N] {
+        let mut result = [0u8; N];
+        let mut i = 0;
+        while i < N {
+            result[i] = self.be_hex_digit(i);
+            i += 1;
+        }
+        result
+    }
+
+    /// Returns the lowercase hex digit at `index` of the big endian hex representation.
+    #[inline(always)]
+    const fn be_hex_digit(&self, index: usize) -> u8 {
+        let offset = (Self::BITS / 4 - 1 - index) * 4;
+        let nibble = (self.limbs[offset / Limb::BITS].0 >> (offset % Limb::BITS)) & 0xf;
+        encode_nibble(nibble as u8)
+    }
+
     /// Serialize this [`Uint`] as big-endian, writing it into the provided
     /// byte slice.
     #[inline]
@@ -156,6 +356,37 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 }
 
+/// Encode a single nibble as a lowercase hex digit
+#[inline(always)]
+const fn encode_nibble(src: u8) -> u8 {
+    let nibble = src as i16;
+
+    // 0-9 map to 0x30-0x39, 10-15 to 0x61-0x66
+    // if (nibble > 9) ret += 0x61 - 0x30 - 10; // 39
+    let ret = nibble + 0x30 + (((9i16 - nibble) >> 8) & 39);
+    ret as u8
+}
+
+/// Check that `bytes` only contains hex digits and underscores after an optional `0x` prefix,
+/// returning the offset of the first digit.
+const fn validate_hex(bytes: &[u8]) -> Result<usize, HexError> {
+    let start = if bytes.len() >= 2 && bytes[0] == b'0' && (bytes[1] == b'x' || bytes[1] == b'X') {
+        2
+    } else {
+        0
+    };
+
+    let mut i = start;
+    while i < bytes.len() {
+        if bytes[i] != b'_' && decode_nibble(bytes[i]) > 0xf {
+            return Err(HexError::InvalidDigit { position: i });
+        }
+        i += 1;
+    }
+
+    Ok(start)
+}
+
 /// Decode a single nibble of upper or lower hex
 #[inline(always)]
 const fn decode_nibble(src: u8) -> u16 {
@@ -189,12 +420,13 @@ const fn decode_hex_byte(bytes: [u8; 2]) -> (u8, u16) {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
-    use crate::Limb;
+    use crate::{HexError, Limb, U128, U64};
     use hex_literal::hex;
 
     #[cfg(feature = "alloc")]
-    use {crate::U128, alloc::format};
+    use alloc::format;
 
     #[cfg(target_pointer_width = "32")]
     use crate::U64 as UintEx;
@@ -289,4 +521,105 @@ mod tests {
         let n = U128::from_be_hex(hex);
         assert_eq!(hex, format!("{:x}", n));
     }
+
+    #[test]
+    fn try_from_be_hex() {
+        let expected = U64::from_u64(0x0011_2233_4455_6677);
+        assert_eq!(U64::try_from_be_hex("0011223344556677"), Ok(expected));
+        assert_eq!(U64::try_from_be_hex("0x11223344556677"), Ok(expected));
+        assert_eq!(U64::try_from_be_hex("0X_0011_2233_4455_6677"), Ok(expected));
+        assert_eq!(U64::try_from_be_hex("0000112233445566_77"), Ok(expected));
+        assert_eq!(U64::try_from_be_hex("f"), Ok(U64::from_u8(0xf)));
+
+        assert_eq!(U64::try_from_be_hex(""), Err(HexError::InvalidLength));
+        assert_eq!(U64::try_from_be_hex("0x__"), Err(HexError::InvalidLength));
+        assert_eq!(
+            U64::try_from_be_hex("00112233x4556677"),
+            Err(HexError::InvalidDigit { position: 8 })
+        );
+        assert_eq!(
+            U64::try_from_be_hex("0x0x11"),
+            Err(HexError::InvalidDigit { position: 3 })
+        );
+        assert_eq!(
+            U64::try_from_be_hex("100112233445566778"),
+            Err(HexError::Overflow)
+        );
+    }
+
+    #[test]
+    fn try_from_le_hex() {
+        let expected = U64::from_u64(0x0011_2233_4455_6677);
+        assert_eq!(U64::try_from_le_hex("7766554433221100"), Ok(expected));
+        assert_eq!(U64::try_from_le_hex("0x77665544332211"), Ok(expected));
+        assert_eq!(
+            U64::try_from_le_hex("7766_5544_3322_1100_0000"),
+            Ok(expected)
+        );
+        assert_eq!(U64::try_from_le_hex("0f"), Ok(U64::from_u8(0xf)));
+
+        assert_eq!(U64::try_from_le_hex("0x"), Err(HexError::InvalidLength));
+        assert_eq!(U64::try_from_le_hex("776"), Err(HexError::InvalidLength));
+        assert_eq!(
+            U64::try_from_le_hex("77 6"),
+            Err(HexError::InvalidDigit { position: 2 })
+        );
+        assert_eq!(
+            U64::try_from_le_hex("776655443322110001"),
+            Err(HexError::Overflow)
+        );
+    }
+
+    #[test]
+    fn to_be_hex_array() {
+        let n = U128::from_be_hex("00112233445566778899AABBCCDDEEFF");
+        assert_eq!(&n.to_be_hex_array(), b"00112233445566778899aabbccddeeff");
+        assert_eq!(U64::ZERO.to_be_hex_array(), [b'0'; 16]);
+        assert_eq!(U64::MAX.to_be_hex_array(), [b'f'; 16]);
+
+        let hex = n.to_be_hex_array();
+        let hex = core::str::from_utf8(&hex).unwrap();
+        assert_eq!(U128::from_be_hex(hex), n);
+    }
+
+    #[test]
+    fn be_bytes_minimal() {
+        let n = U128::from_u64(0x0102);
+        let mut out = [0xffu8; 16];
+        let len = n.to_be_bytes_minimal(&mut out);
+        assert_eq!(&out[..len], &hex!("0102"));
+        assert_eq!(U128::from_be_bytes_minimal(&out[..len]).unwrap(), n);
+
+        assert_eq!(U128::ZERO.to_be_bytes_minimal(&mut out), 0);
+        assert_eq!(U128::from_be_bytes_minimal(&[]).unwrap(), U128::ZERO);
+
+        let len = U128::MAX.to_be_bytes_minimal(&mut out);
+        assert_eq!(&out[..len], &[0xff; 16]);
+        assert_eq!(U128::from_be_bytes_minimal(&out).unwrap(), U128::MAX);
+
+        assert!(bool::from(
+            U128::from_be_bytes_minimal(&hex!("0001")).is_none()
+        ));
+        assert!(bool::from(U128::from_be_bytes_minimal(&[1; 17]).is_none()));
+    }
+
+    #[test]
+    fn be_bytes_padded() {
+        let n = U128::from_u64(0x0102);
+        let mut out = [0xffu8; 20];
+        assert!(n.to_be_bytes_padded(&mut out).is_true_vartime());
+        assert_eq!(&out[..18], &[0; 18]);
+        assert_eq!(&out[18..], &hex!("0102"));
+        assert_eq!(U128::from_be_bytes_padded(&out).unwrap(), n);
+
+        let mut out = [0xffu8; 1];
+        assert!(!n.to_be_bytes_padded(&mut out).is_true_vartime());
+        assert_eq!(out, hex!("02"));
+
+        assert_eq!(
+            U128::from_be_bytes_padded(&hex!("0001")).unwrap(),
+            U128::ONE
+        );
+        assert!(bool::from(U128::from_be_bytes_padded(&[1; 17]).is_none()));
+    }
 }
@@ -0,0 +1,139 @@
+//! `Base64urlUInt` encoding support ([RFC 7518 § 2]), as used by JSON Web Keys.
+//!
+//! The integer is encoded as unpadded base64url of its big-endian bytes without leading
+//! zero bytes. Zero is encoded as a single zero byte, i.e. `AA`.
+//!
+//! [RFC 7518 § 2]: https://datatracker.ietf.org/doc/html/rfc7518#section-2
+
+use crate::{Encoding, Uint};
+use base64ct::{Base64UrlUnpadded, Encoding as _};
+use subtle::{Choice, CtOption};
+
+#[cfg(feature = "alloc")]
+use {crate::BoxedUint, alloc::string::String};
+
+impl<const LIMBS: usize> Uint<LIMBS>
+where
+    Self: Encoding,
+{
+    /// Encode this [`Uint`] as a `Base64urlUInt` into the beginning of `out`,
+    /// returning the encoded string.
+    ///
+    /// NOTE: the length of the encoding is leaked in the time pattern.
+    ///
+    /// # Panics
+    ///
+    /// If `out` is shorter than the encoding, which never happens if it is at least
+    /// `(4 * Uint::BYTES + 2) / 3` bytes long.
+    pub fn to_base64url<'o>(&self, out: &'o mut [u8]) -> &'o str {
+        let mut bytes = self.to_be_bytes();
+        let len = minimal_len(self.to_be_bytes_minimal(bytes.as_mut()));
+        Base64UrlUnpadded::encode(&bytes.as_ref()[..len], out).expect("`out` is too short")
+    }
+
+    /// Decode a [`Uint`] from a `Base64urlUInt`.
+    ///
+    /// Returns `None` if `s` is not valid unpadded base64url, is empty,
+    /// has unnecessary leading zero bytes, or doesn't fit.
+    pub fn from_base64url(s: &str) -> CtOption<Self> {
+        let mut buffer = Self::ZERO.to_be_bytes();
+        match Base64UrlUnpadded::decode(s, buffer.as_mut()) {
+            Ok([]) | Err(_) => CtOption::new(Self::ZERO, Choice::from(0)),
+            Ok(bytes @ [_]) => Self::from_be_bytes_padded(bytes),
+            Ok(bytes) => Self::from_be_bytes_minimal(bytes),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl BoxedUint {
+    /// Encode this [`BoxedUint`] as a `Base64urlUInt`.
+    ///
+    /// NOTE: the length of the encoding is leaked in the time pattern.
+    pub fn to_base64url(&self) -> String {
+        let mut bytes = vec![0u8; self.as_limbs().len() * crate::Limb::BYTES];
+        let len = minimal_len(self.to_be_bytes_minimal(&mut bytes));
+        Base64UrlUnpadded::encode_string(&bytes[..len])
+    }
+
+    /// Decode a [`BoxedUint`] with the given precision from a `Base64urlUInt`.
+    ///
+    /// Returns `None` if `s` is not valid unpadded base64url, is empty,
+    /// has unnecessary leading zero bytes, or doesn't fit,
+    /// or if `bits_precision` is not a multiple of the [`Limb`][`crate::Limb`] size.
+    pub fn from_base64url(s: &str, bits_precision: usize) -> CtOption<Self> {
+        let mut buffer = vec![0u8; (bits_precision + 7) / 8];
+        match Base64UrlUnpadded::decode(s, &mut buffer) {
+            Ok([]) | Err(_) => CtOption::new(Self::zero(), Choice::from(0)),
+            Ok(bytes @ [_]) => Self::from_be_bytes_padded(bytes, bits_precision),
+            Ok(bytes) => Self::from_be_bytes_minimal(bytes, bits_precision),
+        }
+    }
+}
+
+/// Length of the minimal encoding of a value whose big-endian bytes without leading zeros
+/// are `len` bytes long: zero is encoded as a single zero byte.
+fn minimal_len(len: usize) -> usize {
+    len.max(1)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{U128, U64};
+
+    const VECTORS: &[(U64, &str)] = &[
+        (U64::ZERO, "AA"),
+        (U64::ONE, "AQ"),
+        // Public exponent from RFC 7517 Appendix A.1
+        (U64::from_u64(65537), "AQAB"),
+        (U64::MAX, "__________8"),
+    ];
+
+    #[test]
+    fn round_trip() {
+        let mut out = [0u8; 16];
+        for &(uint, expected) in VECTORS {
+            assert_eq!(uint.to_base64url(&mut out), expected);
+            assert_eq!(U64::from_base64url(expected).unwrap(), uint);
+        }
+    }
+
+    #[test]
+    fn reject_invalid() {
+        // Empty
+        assert!(bool::from(U64::from_base64url("").is_none()));
+
+        // Unnecessary leading zero bytes
+        assert!(bool::from(U64::from_base64url("AAE").is_none()));
+        assert!(bool::from(U64::from_base64url("AAA").is_none()));
+
+        // Padding and non-url alphabet
+        assert!(bool::from(U64::from_base64url("AQ==").is_none()));
+        assert!(bool::from(U64::from_base64url("+/8").is_none()));
+
+        // Too big
+        let mut out = [0u8; 32];
+        let encoded = U128::MAX.to_base64url(&mut out);
+        assert!(bool::from(U64::from_base64url(encoded).is_none()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed_round_trip() {
+        use crate::BoxedUint;
+
+        for &(uint, expected) in VECTORS {
+            let boxed = BoxedUint::from_words(uint.as_words());
+            assert_eq!(boxed.to_base64url(), expected);
+
+            let decoded = BoxedUint::from_base64url(expected, 64).unwrap();
+            assert_eq!(decoded.as_words(), uint.as_words());
+        }
+
+        assert!(bool::from(BoxedUint::from_base64url("AAE", 64).is_none()));
+        assert!(bool::from(
+            BoxedUint::from_base64url("AAAAAAAAAAAB", 64).is_none()
+        ));
+    }
+}
@@ -0,0 +1,160 @@
+//! [Borsh] encoding support.
+//!
+//! [`Uint`] is encoded as its fixed-width little endian bytes, while [`BoxedUint`] is encoded
+//! like a `Vec<u8>` of the little endian bytes of its full precision, i.e. with a `u32` length
+//! prefix.
+//!
+//! [Borsh]: https://borsh.io
+
+use crate::{Checked, Limb, NonZero, Uint, Word, Wrapping, Zero};
+use borsh::{
+    io::{self, ErrorKind, Read, Write},
+    BorshDeserialize, BorshSerialize,
+};
+use subtle::{Choice, CtOption};
+
+#[cfg(feature = "alloc")]
+use {crate::BoxedUint, alloc::vec::Vec};
+
+impl<const LIMBS: usize> BorshSerialize for Uint<LIMBS> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        for word in self.as_words() {
+            writer.write_all(&word.to_le_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+impl<const LIMBS: usize> BorshDeserialize for Uint<LIMBS> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut limbs = [Limb::ZERO; LIMBS];
+        for limb in &mut limbs {
+            let mut bytes = [0u8; Limb::BYTES];
+            reader.read_exact(&mut bytes)?;
+            *limb = Limb(Word::from_le_bytes(bytes));
+        }
+        Ok(Self::new(limbs))
+    }
+}
+
+impl<T: BorshSerialize + Zero> BorshSerialize for NonZero<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.as_ref().serialize(writer)
+    }
+}
+
+impl<T: BorshDeserialize + Zero> BorshDeserialize for NonZero<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let value = T::deserialize_reader(reader)?;
+        Option::from(Self::new(value))
+            .ok_or_else(|| io::Error::new(ErrorKind::InvalidData, "expected a non-zero value"))
+    }
+}
+
+impl<T: BorshSerialize> BorshSerialize for Wrapping<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+impl<T: BorshDeserialize> BorshDeserialize for Wrapping<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        T::deserialize_reader(reader).map(Self)
+    }
+}
+
+impl<T: Copy + BorshSerialize> BorshSerialize for Checked<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        Option::<T>::from(self.0).serialize(writer)
+    }
+}
+
+impl<T: Default + BorshDeserialize> BorshDeserialize for Checked<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let value = Option::<T>::deserialize_reader(reader)?;
+        let choice = Choice::from(value.is_some() as u8);
+        Ok(Self(CtOption::new(value.unwrap_or_default(), choice)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl BorshSerialize for BoxedUint {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.to_le_byte_vec().serialize(writer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl BorshDeserialize for BoxedUint {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let bytes = Vec::<u8>::deserialize_reader(reader)?;
+        Self::from_le_byte_slice(&bytes, bytes.len() * 8).ok_or_else(|| {
+            io::Error::new(
+                ErrorKind::InvalidData,
+                "length must be a non-zero multiple of the limb size",
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{Checked, Encoding as _, NonZero, Wrapping, U128, U64};
+    use subtle::ConstantTimeEq;
+
+    #[test]
+    fn uint_round_trip() {
+        let n = U128::from_be_hex("00112233445566778899aabbccddeeff");
+        let encoded = borsh::to_vec(&n).unwrap();
+        assert_eq!(encoded, n.to_le_bytes());
+        assert_eq!(borsh::from_slice::<U128>(&encoded).unwrap(), n);
+
+        assert!(borsh::from_slice::<U128>(&encoded[1..]).is_err());
+    }
+
+    #[test]
+    fn non_zero_round_trip() {
+        let n = NonZero::new(U64::from_u64(42)).unwrap();
+        let encoded = borsh::to_vec(&n).unwrap();
+        assert_eq!(borsh::from_slice::<NonZero<U64>>(&encoded).unwrap(), n);
+
+        let zero = borsh::to_vec(&U64::ZERO).unwrap();
+        assert!(borsh::from_slice::<NonZero<U64>>(&zero).is_err());
+    }
+
+    #[test]
+    fn wrapping_round_trip() {
+        let n = Wrapping(U64::MAX);
+        let encoded = borsh::to_vec(&n).unwrap();
+        assert_eq!(encoded, borsh::to_vec(&U64::MAX).unwrap());
+        assert_eq!(borsh::from_slice::<Wrapping<U64>>(&encoded).unwrap(), n);
+    }
+
+    #[test]
+    fn checked_round_trip() {
+        for n in [
+            Checked::new(U64::from_u64(42)),
+            Checked::new(U64::ZERO) - Checked::new(U64::ONE),
+        ] {
+            let encoded = borsh::to_vec(&n).unwrap();
+            let decoded = borsh::from_slice::<Checked<U64>>(&encoded).unwrap();
+            assert!(bool::from(n.ct_eq(&decoded)));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed_round_trip() {
+        use crate::{BoxedUint, Limb};
+
+        let n = BoxedUint::from_words(&[1, 2]);
+        let encoded = borsh::to_vec(&n).unwrap();
+        assert_eq!(encoded.len(), 4 + 2 * Limb::BYTES);
+        assert_eq!(encoded[..4], ((2 * Limb::BYTES) as u32).to_le_bytes());
+        assert_eq!(borsh::from_slice::<BoxedUint>(&encoded).unwrap(), n);
+
+        assert!(borsh::from_slice::<BoxedUint>(&[0, 0, 0, 0]).is_err());
+        assert!(borsh::from_slice::<BoxedUint>(&[1, 0, 0, 0, 1]).is_err());
+    }
+}
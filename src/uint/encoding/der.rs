@@ -1,6 +1,6 @@
 //! Support for decoding/encoding [`Uint`] as an ASN.1 DER `INTEGER`.
 
-use crate::{generic_array::GenericArray, ArrayEncoding, Uint};
+use crate::{ArrayEncoding, Uint};
 use ::der::{
     asn1::{AnyRef, UintRef},
     DecodeValue, EncodeValue, FixedTag, Length, Tag,
@@ -24,10 +24,8 @@ where
     type Error = der::Error;
 
     fn try_from(bytes: UintRef<'a>) -> der::Result<Uint<LIMBS>> {
-        let mut array = GenericArray::default();
-        let offset = array.len().saturating_sub(bytes.len().try_into()?);
-        array[offset..].copy_from_slice(bytes.as_bytes());
-        Ok(Uint::from_be_byte_array(array))
+        Option::from(Uint::from_be_bytes_padded(bytes.as_bytes()))
+            .ok_or_else(|| Tag::Integer.length_error())
     }
 }
 
@@ -62,3 +60,30 @@ where
 {
     const TAG: Tag = Tag::Integer;
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{U128, U64};
+    use der::{asn1::UintRef, Decode, Encode};
+    use hex_literal::hex;
+
+    #[test]
+    fn round_trip() {
+        let mut buf = [0u8; 32];
+        for n in [U128::ZERO, U128::ONE, U128::from_u64(0x80), U128::MAX] {
+            let encoded = n.encode_to_slice(&mut buf).unwrap();
+            assert_eq!(U128::from_der(encoded).unwrap(), n);
+        }
+
+        let encoded = U128::from_u64(0x80).encode_to_slice(&mut buf).unwrap();
+        assert_eq!(encoded, &hex!("02020080"));
+    }
+
+    #[test]
+    fn too_big() {
+        let bytes = hex!("010000000000000000");
+        let uint = UintRef::new(&bytes).unwrap();
+        assert!(U64::try_from(uint).is_err());
+    }
+}
@@ -0,0 +1,171 @@
+//! OpenPGP multiprecision integer (MPI) encoding support ([RFC 4880 § 3.2]).
+//!
+//! An MPI is a two-octet big-endian count of the significant bits of the integer,
+//! followed by the big-endian bytes of the integer without leading zero bytes.
+//!
+//! [RFC 4880 § 3.2]: https://datatracker.ietf.org/doc/html/rfc4880#section-3.2
+
+use crate::{limb_slice, Limb, Uint};
+use subtle::{Choice, ConstantTimeEq, CtOption};
+
+#[cfg(feature = "alloc")]
+use crate::BoxedUint;
+
+/// Length of the bit count prefix.
+const PREFIX_LEN: usize = 2;
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Encode this [`Uint`] as an OpenPGP MPI, including its bit count prefix,
+    /// into the beginning of `out`, returning the number of bytes written.
+    ///
+    /// NOTE: the length of the encoding is leaked in the time pattern.
+    ///
+    /// # Panics
+    ///
+    /// If `out` is shorter than the encoding, which never happens if it is at least
+    /// [`Uint::BYTES`] + 2 bytes long, or if the value has more than 65535 bits.
+    pub fn to_pgp_mpi(&self, out: &mut [u8]) -> usize {
+        encode(&self.limbs, out)
+    }
+
+    /// Decode a [`Uint`] from an OpenPGP MPI, including its bit count prefix.
+    ///
+    /// Returns `None` if the bit count doesn't match the value or the length of `bytes`,
+    /// or if the value doesn't fit.
+    pub fn from_pgp_mpi(bytes: &[u8]) -> CtOption<Self> {
+        let mut limbs = [Limb::ZERO; LIMBS];
+        let is_valid = decode(&mut limbs, bytes);
+        CtOption::new(Uint::new(limbs), is_valid)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl BoxedUint {
+    /// Encode this [`BoxedUint`] as an OpenPGP MPI, including its bit count prefix,
+    /// into the beginning of `out`, returning the number of bytes written.
+    ///
+    /// NOTE: the length of the encoding is leaked in the time pattern.
+    ///
+    /// # Panics
+    ///
+    /// If `out` is shorter than the encoding, or if the value has more than 65535 bits.
+    pub fn to_pgp_mpi(&self, out: &mut [u8]) -> usize {
+        encode(self.as_limbs(), out)
+    }
+
+    /// Decode a [`BoxedUint`] with the given precision from an OpenPGP MPI,
+    /// including its bit count prefix.
+    ///
+    /// Returns `None` if the bit count doesn't match the value or the length of `bytes`,
+    /// if the value doesn't fit, or if `bits_precision` is not a multiple of the
+    /// [`Limb`] size.
+    pub fn from_pgp_mpi(bytes: &[u8], bits_precision: usize) -> CtOption<Self> {
+        match Self::new(bits_precision) {
+            Some(mut uint) => {
+                let is_valid = decode(uint.as_limbs_mut(), bytes);
+                CtOption::new(uint, is_valid)
+            }
+            None => CtOption::new(Self::zero(), Choice::from(0)),
+        }
+    }
+}
+
+fn encode(limbs: &[Limb], out: &mut [u8]) -> usize {
+    assert!(out.len() >= PREFIX_LEN, "`out` is too short");
+
+    let len = limb_slice::to_be_bytes_minimal(limbs, &mut out[PREFIX_LEN..]);
+    let bits = match out.get(PREFIX_LEN) {
+        Some(&first) if len > 0 => (len - 1) * 8 + (8 - first.leading_zeros() as usize),
+        _ => 0,
+    };
+
+    let prefix = u16::try_from(bits).expect("integer is too long to be encoded");
+    out[..PREFIX_LEN].copy_from_slice(&prefix.to_be_bytes());
+    PREFIX_LEN + len
+}
+
+/// Decodes `bytes` into `limbs`, which must be zeroed.
+fn decode(limbs: &mut [Limb], bytes: &[u8]) -> Choice {
+    if bytes.len() < PREFIX_LEN {
+        return Choice::from(0);
+    }
+
+    let (prefix, body) = bytes.split_at(PREFIX_LEN);
+    let bits = u16::from_be_bytes([prefix[0], prefix[1]]) as usize;
+    if body.len() != (bits + 7) / 8 {
+        return Choice::from(0);
+    }
+
+    // The most significant bit of the first byte must be the one given by the bit count
+    let is_minimal = match body.first() {
+        Some(first) => (first >> ((bits - 1) % 8)).ct_eq(&1),
+        None => Choice::from(1),
+    };
+
+    let fits = limb_slice::from_be_bytes_padded(limbs, body);
+    Choice::from(fits) & is_minimal
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{U128, U64};
+    use hex_literal::hex;
+
+    const VECTORS: &[(U64, &[u8])] = &[
+        (U64::ZERO, &hex!("0000")),
+        (U64::ONE, &hex!("0001 01")),
+        // Example from RFC 4880 § 3.2
+        (U64::from_u64(511), &hex!("0009 01ff")),
+        (U64::MAX, &hex!("0040 ffffffffffffffff")),
+    ];
+
+    #[test]
+    fn round_trip() {
+        let mut out = [0u8; 16];
+        for &(uint, expected) in VECTORS {
+            let len = uint.to_pgp_mpi(&mut out);
+            assert_eq!(&out[..len], expected);
+            assert_eq!(U64::from_pgp_mpi(expected).unwrap(), uint);
+        }
+    }
+
+    #[test]
+    fn reject_invalid() {
+        // Bit count doesn't match the value
+        assert!(bool::from(U64::from_pgp_mpi(&hex!("0008 01")).is_none()));
+        assert!(bool::from(U64::from_pgp_mpi(&hex!("0001 03")).is_none()));
+        assert!(bool::from(U64::from_pgp_mpi(&hex!("0009 00ff")).is_none()));
+        assert!(bool::from(U64::from_pgp_mpi(&hex!("0010 00ff")).is_none()));
+
+        // Bit count doesn't match the length
+        assert!(bool::from(U64::from_pgp_mpi(&hex!("00")).is_none()));
+        assert!(bool::from(U64::from_pgp_mpi(&hex!("0009 ff")).is_none()));
+        assert!(bool::from(U64::from_pgp_mpi(&hex!("0000 00")).is_none()));
+
+        // Too big
+        let mut out = [0u8; 32];
+        let len = U128::MAX.to_pgp_mpi(&mut out);
+        assert!(bool::from(U64::from_pgp_mpi(&out[..len]).is_none()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed_round_trip() {
+        use crate::BoxedUint;
+
+        let mut out = [0u8; 16];
+        for &(uint, expected) in VECTORS {
+            let boxed = BoxedUint::from_words(uint.as_words());
+            let len = boxed.to_pgp_mpi(&mut out);
+            assert_eq!(&out[..len], expected);
+
+            let decoded = BoxedUint::from_pgp_mpi(expected, 64).unwrap();
+            assert_eq!(decoded.as_words(), uint.as_words());
+        }
+
+        assert!(bool::from(
+            BoxedUint::from_pgp_mpi(&hex!("0009 ff"), 64).is_none()
+        ));
+    }
+}
@@ -8,36 +8,23 @@ where
     Self: Encoding,
 {
     fn rlp_append(&self, stream: &mut RlpStream) {
-        let bytes = self.to_be_bytes();
-        let mut bytes_stripped = bytes.as_ref();
-
-        while bytes_stripped.first().cloned() == Some(0) {
-            bytes_stripped = &bytes_stripped[1..];
-        }
-
-        stream.encoder().encode_value(bytes_stripped);
+        let mut bytes = self.to_be_bytes();
+        let len = self.to_be_bytes_minimal(bytes.as_mut());
+        stream.encoder().encode_value(&bytes.as_ref()[..len]);
     }
 }
 
 impl<const LIMBS: usize> rlp::Decodable for Uint<LIMBS>
 where
     Self: Encoding,
-    <Self as Encoding>::Repr: Default,
 {
     fn decode(rlp: &Rlp<'_>) -> Result<Self, DecoderError> {
         rlp.decoder().decode_value(|bytes| {
-            if bytes.first().cloned() == Some(0) {
-                Err(rlp::DecoderError::RlpInvalidIndirection)
+            if bytes.len() > Self::BYTES {
+                Err(DecoderError::RlpIsTooBig)
             } else {
-                let mut repr = <Self as Encoding>::Repr::default();
-                let offset = repr
-                    .as_ref()
-                    .len()
-                    .checked_sub(bytes.len())
-                    .ok_or(DecoderError::RlpIsTooBig)?;
-
-                repr.as_mut()[offset..].copy_from_slice(bytes);
-                Ok(Self::from_be_bytes(repr))
+                Option::from(Self::from_be_bytes_minimal(bytes))
+                    .ok_or(DecoderError::RlpInvalidIndirection)
             }
         })
     }
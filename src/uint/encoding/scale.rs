@@ -0,0 +1,222 @@
+//! [SCALE] encoding support, via `parity-scale-codec`.
+//!
+//! [`Uint`] is encoded as its fixed-width little endian bytes, while [`BoxedUint`] is encoded
+//! like a `Vec<u8>` of the little endian bytes of its full precision, i.e. with a compact
+//! length prefix.
+//!
+//! [SCALE]: https://docs.substrate.io/reference/scale-codec/
+
+use crate::{Checked, Limb, NonZero, Uint, Word, Wrapping, Zero};
+use parity_scale_codec::{Decode, Encode, EncodeLike, Error, Input, MaxEncodedLen, Output};
+use subtle::{Choice, CtOption};
+
+#[cfg(feature = "alloc")]
+use {crate::BoxedUint, alloc::vec::Vec, parity_scale_codec::Compact};
+
+impl<const LIMBS: usize> Encode for Uint<LIMBS> {
+    fn size_hint(&self) -> usize {
+        Self::BYTES
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        for word in self.as_words() {
+            dest.write(&word.to_le_bytes());
+        }
+    }
+}
+
+impl<const LIMBS: usize> Decode for Uint<LIMBS> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let mut limbs = [Limb::ZERO; LIMBS];
+        for limb in &mut limbs {
+            let mut bytes = [0u8; Limb::BYTES];
+            input.read(&mut bytes)?;
+            *limb = Limb(Word::from_le_bytes(bytes));
+        }
+        Ok(Self::new(limbs))
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(Self::BYTES)
+    }
+}
+
+impl<const LIMBS: usize> EncodeLike for Uint<LIMBS> {}
+
+impl<const LIMBS: usize> MaxEncodedLen for Uint<LIMBS> {
+    fn max_encoded_len() -> usize {
+        Self::BYTES
+    }
+}
+
+impl<T: Encode + Zero> Encode for NonZero<T> {
+    fn size_hint(&self) -> usize {
+        self.as_ref().size_hint()
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        self.as_ref().encode_to(dest)
+    }
+}
+
+impl<T: Decode + Zero> Decode for NonZero<T> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let value = T::decode(input)?;
+        Option::from(Self::new(value)).ok_or_else(|| "expected a non-zero value".into())
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        T::encoded_fixed_size()
+    }
+}
+
+impl<T: Encode + Zero> EncodeLike for NonZero<T> {}
+
+impl<T: MaxEncodedLen + Zero> MaxEncodedLen for NonZero<T> {
+    fn max_encoded_len() -> usize {
+        T::max_encoded_len()
+    }
+}
+
+impl<T: Encode> Encode for Wrapping<T> {
+    fn size_hint(&self) -> usize {
+        self.0.size_hint()
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        self.0.encode_to(dest)
+    }
+}
+
+impl<T: Decode> Decode for Wrapping<T> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        T::decode(input).map(Self)
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        T::encoded_fixed_size()
+    }
+}
+
+impl<T: Encode> EncodeLike for Wrapping<T> {}
+
+impl<T: MaxEncodedLen> MaxEncodedLen for Wrapping<T> {
+    fn max_encoded_len() -> usize {
+        T::max_encoded_len()
+    }
+}
+
+impl<T: Copy + Encode> Encode for Checked<T> {
+    fn size_hint(&self) -> usize {
+        Option::<T>::from(self.0).size_hint()
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        Option::<T>::from(self.0).encode_to(dest)
+    }
+}
+
+impl<T: Default + Decode> Decode for Checked<T> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let value = Option::<T>::decode(input)?;
+        let choice = Choice::from(value.is_some() as u8);
+        Ok(Self(CtOption::new(value.unwrap_or_default(), choice)))
+    }
+}
+
+impl<T: Copy + Encode> EncodeLike for Checked<T> {}
+
+impl<T: Copy + MaxEncodedLen> MaxEncodedLen for Checked<T> {
+    fn max_encoded_len() -> usize {
+        Option::<T>::max_encoded_len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Encode for BoxedUint {
+    fn size_hint(&self) -> usize {
+        let len = self.as_limbs().len() * Limb::BYTES;
+        Compact(len as u32).size_hint() + len
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        self.to_le_byte_vec().encode_to(dest)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Decode for BoxedUint {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let bytes = Vec::<u8>::decode(input)?;
+        Self::from_le_byte_slice(&bytes, bytes.len() * 8)
+            .ok_or_else(|| "length must be a non-zero multiple of the limb size".into())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl EncodeLike for BoxedUint {}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{Checked, Encoding as _, NonZero, Wrapping, U128, U64};
+    use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+    use subtle::ConstantTimeEq;
+
+    #[test]
+    fn uint_round_trip() {
+        let n = U128::from_be_hex("00112233445566778899aabbccddeeff");
+        let encoded = n.encode();
+        assert_eq!(encoded, n.to_le_bytes());
+        assert_eq!(encoded.len(), U128::max_encoded_len());
+        assert_eq!(U128::decode(&mut encoded.as_slice()).unwrap(), n);
+
+        assert!(U128::decode(&mut &encoded[1..]).is_err());
+    }
+
+    #[test]
+    fn non_zero_round_trip() {
+        let n = NonZero::new(U64::from_u64(42)).unwrap();
+        let encoded = n.encode();
+        assert_eq!(NonZero::<U64>::decode(&mut encoded.as_slice()).unwrap(), n);
+
+        let zero = U64::ZERO.encode();
+        assert!(NonZero::<U64>::decode(&mut zero.as_slice()).is_err());
+    }
+
+    #[test]
+    fn wrapping_round_trip() {
+        let n = Wrapping(U64::MAX);
+        let encoded = n.encode();
+        assert_eq!(encoded, U64::MAX.encode());
+        assert_eq!(Wrapping::<U64>::decode(&mut encoded.as_slice()).unwrap(), n);
+    }
+
+    #[test]
+    fn checked_round_trip() {
+        for n in [
+            Checked::new(U64::from_u64(42)),
+            Checked::new(U64::ZERO) - Checked::new(U64::ONE),
+        ] {
+            let encoded = n.encode();
+            assert!(encoded.len() <= Checked::<U64>::max_encoded_len());
+            let decoded = Checked::<U64>::decode(&mut encoded.as_slice()).unwrap();
+            assert!(bool::from(n.ct_eq(&decoded)));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed_round_trip() {
+        use crate::{BoxedUint, Limb};
+
+        let n = BoxedUint::from_words(&[1, 2]);
+        let encoded = n.encode();
+        assert_eq!(encoded.len(), n.size_hint());
+        assert_eq!(encoded[0], (2 * Limb::BYTES as u8) << 2);
+        assert_eq!(BoxedUint::decode(&mut encoded.as_slice()).unwrap(), n);
+
+        assert!(BoxedUint::decode(&mut [0u8].as_slice()).is_err());
+        assert!(BoxedUint::decode(&mut [4u8, 1].as_slice()).is_err());
+    }
+}
@@ -0,0 +1,203 @@
+//! SSH `mpint` encoding support ([RFC 4251 § 5]).
+//!
+//! An `mpint` is a `uint32` byte length followed by the two's complement big-endian
+//! representation of the integer, without unnecessary leading bytes. Zero is encoded
+//! with no bytes, and positive integers whose most significant bit is set get a leading
+//! zero byte.
+//!
+//! [RFC 4251 § 5]: https://datatracker.ietf.org/doc/html/rfc4251#section-5
+
+use crate::{limb_slice, Limb, Uint};
+use subtle::{Choice, ConstantTimeEq, CtOption};
+
+#[cfg(feature = "alloc")]
+use crate::BoxedUint;
+
+/// Length of the `uint32` length prefix.
+const PREFIX_LEN: usize = 4;
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Encode this [`Uint`] as an SSH `mpint`, including its length prefix,
+    /// into the beginning of `out`, returning the number of bytes written.
+    ///
+    /// NOTE: the length of the encoding is leaked in the time pattern.
+    ///
+    /// # Panics
+    ///
+    /// If `out` is shorter than the encoding, which never happens if it is at least
+    /// [`Uint::BYTES`] + 5 bytes long.
+    pub fn to_ssh_mpint(&self, out: &mut [u8]) -> usize {
+        encode(&self.limbs, out)
+    }
+
+    /// Decode a [`Uint`] from an SSH `mpint`, including its length prefix.
+    ///
+    /// Returns `None` if the length prefix doesn't match the length of `bytes`,
+    /// the integer is negative, has unnecessary leading bytes, or doesn't fit.
+    pub fn from_ssh_mpint(bytes: &[u8]) -> CtOption<Self> {
+        let mut limbs = [Limb::ZERO; LIMBS];
+        let is_valid = decode(&mut limbs, bytes);
+        CtOption::new(Uint::new(limbs), is_valid)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl BoxedUint {
+    /// Encode this [`BoxedUint`] as an SSH `mpint`, including its length prefix,
+    /// into the beginning of `out`, returning the number of bytes written.
+    ///
+    /// NOTE: the length of the encoding is leaked in the time pattern.
+    ///
+    /// # Panics
+    ///
+    /// If `out` is shorter than the encoding.
+    pub fn to_ssh_mpint(&self, out: &mut [u8]) -> usize {
+        encode(self.as_limbs(), out)
+    }
+
+    /// Decode a [`BoxedUint`] with the given precision from an SSH `mpint`,
+    /// including its length prefix.
+    ///
+    /// Returns `None` if the length prefix doesn't match the length of `bytes`,
+    /// the integer is negative, has unnecessary leading bytes, or doesn't fit,
+    /// or if `bits_precision` is not a multiple of the [`Limb`] size.
+    pub fn from_ssh_mpint(bytes: &[u8], bits_precision: usize) -> CtOption<Self> {
+        match Self::new(bits_precision) {
+            Some(mut uint) => {
+                let is_valid = decode(uint.as_limbs_mut(), bytes);
+                CtOption::new(uint, is_valid)
+            }
+            None => CtOption::new(Self::zero(), Choice::from(0)),
+        }
+    }
+}
+
+fn encode(limbs: &[Limb], out: &mut [u8]) -> usize {
+    assert!(out.len() >= PREFIX_LEN, "`out` is too short");
+
+    let mut len = limb_slice::to_be_bytes_minimal(limbs, &mut out[PREFIX_LEN..]);
+
+    // A set high bit would make the integer negative
+    if len > 0 && out[PREFIX_LEN] & 0x80 != 0 {
+        assert!(out.len() > PREFIX_LEN + len, "`out` is too short");
+        out.copy_within(PREFIX_LEN..PREFIX_LEN + len, PREFIX_LEN + 1);
+        out[PREFIX_LEN] = 0;
+        len += 1;
+    }
+
+    let prefix = u32::try_from(len).expect("integer is too long to be encoded");
+    out[..PREFIX_LEN].copy_from_slice(&prefix.to_be_bytes());
+    PREFIX_LEN + len
+}
+
+/// Decodes `bytes` into `limbs`, which must be zeroed.
+fn decode(limbs: &mut [Limb], bytes: &[u8]) -> Choice {
+    if bytes.len() < PREFIX_LEN {
+        return Choice::from(0);
+    }
+
+    let (prefix, body) = bytes.split_at(PREFIX_LEN);
+    let mut len = [0u8; PREFIX_LEN];
+    len.copy_from_slice(prefix);
+    if u32::from_be_bytes(len) as usize != body.len() {
+        return Choice::from(0);
+    }
+
+    let first = body.first().copied().unwrap_or(0);
+    let is_negative = Choice::from(first >> 7);
+
+    // A leading zero byte is only allowed to clear the high bit of the next one
+    let next_high_bit = body.get(1).map_or(0, |byte| byte >> 7);
+    let is_padded = Choice::from(!body.is_empty() as u8) & first.ct_eq(&0);
+    let is_non_minimal = is_padded & next_high_bit.ct_eq(&0);
+
+    let fits = limb_slice::from_be_bytes_padded(limbs, body);
+    Choice::from(fits) & !is_negative & !is_non_minimal
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{U128, U64};
+    use hex_literal::hex;
+
+    /// Test vectors from RFC 4251 § 5.
+    const VECTORS: &[(U64, &[u8])] = &[
+        (U64::ZERO, &hex!("00000000")),
+        (
+            U64::from_u64(0x9a378f9b2e332a7),
+            &hex!("00000008 09a378f9b2e332a7"),
+        ),
+        (U64::from_u64(0x80), &hex!("00000002 0080")),
+    ];
+
+    #[test]
+    fn round_trip() {
+        let mut out = [0u8; 16];
+        for &(uint, expected) in VECTORS {
+            let len = uint.to_ssh_mpint(&mut out);
+            assert_eq!(&out[..len], expected);
+            assert_eq!(U64::from_ssh_mpint(expected).unwrap(), uint);
+        }
+
+        let len = U64::MAX.to_ssh_mpint(&mut out);
+        assert_eq!(&out[..len], &hex!("00000009 00ffffffffffffffff"));
+        assert_eq!(U64::from_ssh_mpint(&out[..len]).unwrap(), U64::MAX);
+    }
+
+    #[test]
+    fn reject_invalid() {
+        // Negative numbers
+        assert!(bool::from(
+            U64::from_ssh_mpint(&hex!("00000002 edcc")).is_none()
+        ));
+        assert!(bool::from(
+            U64::from_ssh_mpint(&hex!("00000001 80")).is_none()
+        ));
+
+        // Unnecessary leading zeros
+        assert!(bool::from(
+            U64::from_ssh_mpint(&hex!("00000001 00")).is_none()
+        ));
+        assert!(bool::from(
+            U64::from_ssh_mpint(&hex!("00000002 0001")).is_none()
+        ));
+        assert!(bool::from(
+            U64::from_ssh_mpint(&hex!("00000003 000080")).is_none()
+        ));
+
+        // Length mismatch
+        assert!(bool::from(U64::from_ssh_mpint(&hex!("000000")).is_none()));
+        assert!(bool::from(
+            U64::from_ssh_mpint(&hex!("00000002 01")).is_none()
+        ));
+        assert!(bool::from(
+            U64::from_ssh_mpint(&hex!("00000001 0101")).is_none()
+        ));
+
+        // Too big
+        let mut out = [0u8; 32];
+        let len = U128::MAX.to_ssh_mpint(&mut out);
+        assert!(bool::from(U64::from_ssh_mpint(&out[..len]).is_none()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed_round_trip() {
+        use crate::BoxedUint;
+
+        let mut out = [0u8; 32];
+        for &(uint, expected) in VECTORS {
+            let boxed = BoxedUint::from_words(uint.as_words());
+            let len = boxed.to_ssh_mpint(&mut out);
+            assert_eq!(&out[..len], expected);
+
+            let decoded = BoxedUint::from_ssh_mpint(expected, 64).unwrap();
+            assert_eq!(decoded.as_words(), uint.as_words());
+        }
+
+        assert!(bool::from(
+            BoxedUint::from_ssh_mpint(&hex!("00000002 edcc"), 64).is_none()
+        ));
+    }
+}
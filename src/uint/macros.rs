@@ -6,6 +6,14 @@ macro_rules! impl_uint_aliases {
             #[doc="unsigned big integer."]
             pub type $name = Uint<{nlimbs!($bits)}>;
 
+            impl $name {
+                /// Serialize this integer as a big endian lowercase hex string,
+                /// without allocating.
+                pub const fn to_be_hex_array(&self) -> [u8; $bits / 4] {
+                    self.be_hex_array()
+                }
+            }
+
             impl Encoding for $name {
 
                 type Repr = [u8; $bits / 8];
@@ -34,6 +42,39 @@ macro_rules! impl_uint_aliases {
                     result
                 }
             }
+
+            impl Encoding for Checked<$name> {
+
+                type Repr = [u8; $bits / 8 + 1];
+
+                #[inline]
+                fn from_be_bytes(bytes: Self::Repr) -> Self {
+                    Self::from_flagged(<$name>::from_be_slice(&bytes[1..]), bytes[0])
+                }
+
+                #[inline]
+                fn from_le_bytes(bytes: Self::Repr) -> Self {
+                    Self::from_flagged(<$name>::from_le_slice(&bytes[..$bits / 8]), bytes[$bits / 8])
+                }
+
+                #[inline]
+                fn to_be_bytes(&self) -> Self::Repr {
+                    let (value, flag) = self.to_flagged();
+                    let mut result = [0u8; $bits / 8 + 1];
+                    result[0] = flag;
+                    value.write_be_bytes(&mut result[1..]);
+                    result
+                }
+
+                #[inline]
+                fn to_le_bytes(&self) -> Self::Repr {
+                    let (value, flag) = self.to_flagged();
+                    let mut result = [0u8; $bits / 8 + 1];
+                    value.write_le_bytes(&mut result[..$bits / 8]);
+                    result[$bits / 8] = flag;
+                    result
+                }
+            }
         )+
      };
 }
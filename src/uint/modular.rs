@@ -12,8 +12,16 @@ mod mul;
 mod pow;
 mod sub;
 
+pub use pow::{Window, Window1, Window2, Window3, Window4, Window5, Window6};
 pub use reduction::montgomery_reduction;
 
+use crate::{Invert, PowBoundedExp, UintLike, Unsigned};
+use core::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use subtle::{ConstantTimeEq, CtOption};
+
 /// A generalization for numbers kept in optimized representations (e.g. Montgomery)
 /// that can be converted back to the original form.
 pub trait Retrieve {
@@ -24,12 +32,84 @@ pub trait Retrieve {
     fn retrieve(&self) -> Self::Output;
 }
 
+/// Residues kept in Montgomery form, whether their modulus is fixed at compile time
+/// ([`Residue`][`constant_mod::Residue`]) or set at runtime
+/// ([`DynResidue`][`runtime_mod::DynResidue`]).
+///
+/// Modular addition, subtraction, multiplication and negation are available through the
+/// operators, inversion through [`Invert`] and exponentiation through [`PowBoundedExp`],
+/// so algorithms like batch inversion or square roots can be written once for both.
+pub trait Monty:
+    'static
+    + Clone
+    + ConstantTimeEq
+    + Debug
+    + Eq
+    + Send
+    + Sync
+    + Sized
+    + Add<Output = Self>
+    + for<'a> Add<&'a Self, Output = Self>
+    + AddAssign
+    + for<'a> AddAssign<&'a Self>
+    + Sub<Output = Self>
+    + for<'a> Sub<&'a Self, Output = Self>
+    + SubAssign
+    + for<'a> SubAssign<&'a Self>
+    + Mul<Output = Self>
+    + for<'a> Mul<&'a Self, Output = Self>
+    + MulAssign
+    + for<'a> MulAssign<&'a Self>
+    + Neg<Output = Self>
+    + Invert<Output = CtOption<Self>>
+    + PowBoundedExp<Self::Integer>
+    + Retrieve<Output = Self::Integer>
+{
+    /// The integer type residues are represented with.
+    type Integer: Unsigned;
+
+    /// The parameters describing the modulus.
+    ///
+    /// For residues with a constant modulus this carries no data, as the modulus is part of the type.
+    type Params: 'static + Clone + Debug + Eq + Send + Sync;
+
+    /// Instantiates a new residue that represents `integer` mod the modulus described by `params`.
+    fn new(integer: &Self::Integer, params: Self::Params) -> Self;
+
+    /// Instantiates a new residue that represents zero.
+    fn zero(params: Self::Params) -> Self;
+
+    /// Instantiates a new residue that represents 1.
+    fn one(params: Self::Params) -> Self;
+
+    /// Returns the parameters of this residue.
+    fn params(&self) -> &Self::Params;
+
+    /// Access the value in Montgomery form.
+    fn as_montgomery(&self) -> &Self::Integer;
+
+    /// Computes `self * self`; may be more efficient than a multiplication.
+    fn square(&self) -> Self;
+
+    /// Raises to the `exponent` power.
+    fn pow(&self, exponent: &Self::Integer) -> Self {
+        self.pow_bounded_exp(exponent, exponent.bits_precision())
+    }
+
+    /// Performs the modular division by 2, that is returns `y` such that `y * 2 = self mod p`.
+    fn div_by_2(&self) -> Self;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         const_residue, impl_modulus,
         modular::{
-            constant_mod::Residue, constant_mod::ResidueParams, reduction::montgomery_reduction,
+            constant_mod::Residue,
+            constant_mod::ResidueParams,
+            reduction::montgomery_reduction,
+            runtime_mod::{DynResidue, DynResidueParams},
+            Monty,
         },
         NonZero, Uint, U256, U64,
     };
@@ -161,4 +241,54 @@ mod tests {
             const_residue!(x, Modulus2)
         );
     }
+
+    /// Inverts all of `values` with a single inversion using Montgomery's trick.
+    fn batch_invert<T: Monty>(values: &[T; 3]) -> [T; 3] {
+        let mut prefix = values.clone();
+        for i in 1..prefix.len() {
+            prefix[i] = prefix[i - 1].clone() * &values[i];
+        }
+
+        let mut inv = prefix[2].invert().unwrap();
+        let mut result = values.clone();
+        for i in (1..result.len()).rev() {
+            result[i] = inv.clone() * &prefix[i - 1];
+            inv *= &values[i];
+        }
+        result[0] = inv;
+        result
+    }
+
+    /// Checks `x^(p-1) == 1` and that the results of `batch_invert` are inverses.
+    fn check_generic<T: Monty<Integer = U256>>(params: T::Params, p_minus_1: &U256, xs: [U256; 3]) {
+        let values = xs.map(|x| T::new(&x, params.clone()));
+        let one = T::one(params.clone());
+
+        for (x, x_inv) in values.iter().zip(batch_invert(&values).iter()) {
+            assert_eq!(x.clone() * x_inv, one);
+            assert_eq!(x.pow(p_minus_1), one);
+            assert_eq!(x.div_by_2() + x.div_by_2(), x.clone());
+            assert_eq!(x.square(), x.clone() * x);
+        }
+
+        assert_eq!(T::zero(params.clone()) - &one, -one.clone());
+        assert_eq!(values[0].params(), &params);
+    }
+
+    #[test]
+    fn test_monty_generic() {
+        let xs = [
+            U256::from_u64(2),
+            U256::from_be_hex("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56"),
+            Modulus2::MODULUS.wrapping_sub(&U256::ONE),
+        ];
+        let p_minus_1 = Modulus2::MODULUS.wrapping_sub(&U256::ONE);
+
+        check_generic::<Residue<Modulus2, { Modulus2::LIMBS }>>(Default::default(), &p_minus_1, xs);
+        check_generic::<DynResidue<{ Modulus2::LIMBS }>>(
+            DynResidueParams::new(&Modulus2::MODULUS),
+            &p_minus_1,
+            xs,
+        );
+    }
 }
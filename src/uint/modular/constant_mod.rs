@@ -2,16 +2,24 @@ use core::{fmt::Debug, marker::PhantomData};
 
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
-use crate::{Limb, Uint, Zero};
+use crate::{Encoding, Limb, Uint, UintLike, Zero};
 
-use super::{div_by_2::div_by_2, reduction::montgomery_reduction, Retrieve};
+use super::{div_by_2::div_by_2, reduction::redc, Monty, Retrieve};
+
+#[cfg(any(feature = "arbitrary", feature = "rand_core"))]
+use crate::NonZero;
 
 #[cfg(feature = "rand_core")]
-use crate::{rand_core::CryptoRngCore, NonZero, Random, RandomMod};
+use crate::{rand_core::CryptoRngCore, Random, RandomMod};
+
+#[cfg(all(feature = "der", feature = "generic-array"))]
+use ::der::{DecodeValue, EncodeValue, FixedTag, Length, Tag};
+
+#[cfg(feature = "generic-array")]
+use crate::{ArrayEncoding, ByteArray};
 
 #[cfg(feature = "serde")]
 use {
-    crate::Encoding,
     serdect::serde::de::Error,
     serdect::serde::{Deserialize, Deserializer, Serialize, Serializer},
 };
@@ -36,6 +44,7 @@ mod const_sub;
 #[macro_use]
 mod macros;
 
+#[allow(unused_imports)]
 pub use macros::*;
 
 /// The parameters to efficiently go to and from the Montgomery form for a given odd modulus. An easy way to generate these parameters is using the `impl_modulus!` macro. These parameters are constant, so they cannot be set at runtime.
@@ -123,9 +132,7 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
             };
         }
 
-        let product = integer.mul_wide(&MOD::R2);
-        let montgomery_form =
-            montgomery_reduction::<LIMBS>(&product, &MOD::MODULUS, MOD::MOD_NEG_INV);
+        let montgomery_form = redc(integer.mul_wide(&MOD::R2), &MOD::MODULUS, MOD::MOD_NEG_INV);
 
         Self {
             montgomery_form,
@@ -164,8 +171,8 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
             return self.montgomery_form;
         }
 
-        montgomery_reduction::<LIMBS>(
-            &(self.montgomery_form, Uint::ZERO),
+        redc(
+            (self.montgomery_form, Uint::ZERO),
             &MOD::MODULUS,
             MOD::MOD_NEG_INV,
         )
@@ -207,6 +214,70 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
     }
 }
 
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    /// Encodes the canonical (non-Montgomery) value of this residue as big endian bytes.
+    pub fn to_be_bytes(&self) -> <Uint<LIMBS> as Encoding>::Repr {
+        self.retrieve().to_be_bytes()
+    }
+
+    /// Encodes the canonical (non-Montgomery) value of this residue as little endian bytes.
+    pub fn to_le_bytes(&self) -> <Uint<LIMBS> as Encoding>::Repr {
+        self.retrieve().to_le_bytes()
+    }
+
+    /// Decodes a residue from the big endian bytes of its canonical value.
+    ///
+    /// Returns `None` if the value is not less than the modulus. The check is constant-time.
+    pub fn from_be_bytes_canonical(bytes: <Uint<LIMBS> as Encoding>::Repr) -> CtOption<Self> {
+        Self::from_canonical(Uint::from_be_bytes(bytes))
+    }
+
+    /// Decodes a residue from the little endian bytes of its canonical value.
+    ///
+    /// Returns `None` if the value is not less than the modulus. The check is constant-time.
+    pub fn from_le_bytes_canonical(bytes: <Uint<LIMBS> as Encoding>::Repr) -> CtOption<Self> {
+        Self::from_canonical(Uint::from_le_bytes(bytes))
+    }
+
+    fn from_canonical(integer: Uint<LIMBS>) -> CtOption<Self> {
+        let is_reduced = Uint::ct_lt(&integer, &MOD::MODULUS);
+        CtOption::new(Self::new(&integer), is_reduced.into())
+    }
+}
+
+#[cfg(feature = "generic-array")]
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS>
+where
+    Uint<LIMBS>: ArrayEncoding,
+{
+    /// Encodes the canonical (non-Montgomery) value of this residue as a big endian byte array.
+    pub fn to_be_byte_array(&self) -> ByteArray<Uint<LIMBS>> {
+        self.retrieve().to_be_byte_array()
+    }
+
+    /// Encodes the canonical (non-Montgomery) value of this residue as a little endian byte array.
+    pub fn to_le_byte_array(&self) -> ByteArray<Uint<LIMBS>> {
+        self.retrieve().to_le_byte_array()
+    }
+
+    /// Decodes a residue from the big endian byte array of its canonical value.
+    ///
+    /// Returns `None` if the value is not less than the modulus. The check is constant-time.
+    pub fn from_be_byte_array_canonical(bytes: ByteArray<Uint<LIMBS>>) -> CtOption<Self> {
+        Self::from_canonical(Uint::from_be_byte_array(bytes))
+    }
+
+    /// Decodes a residue from the little endian byte array of its canonical value.
+    ///
+    /// Returns `None` if the value is not less than the modulus. The check is constant-time.
+    pub fn from_le_byte_array_canonical(bytes: ByteArray<Uint<LIMBS>>) -> CtOption<Self> {
+        Self::from_canonical(Uint::from_le_byte_array(bytes))
+    }
+}
+
 impl<MOD: ResidueParams<LIMBS> + Copy, const LIMBS: usize> ConditionallySelectable
     for Residue<MOD, LIMBS>
 {
@@ -238,6 +309,24 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Zero for Residue<MOD, LIMBS>
     const ZERO: Self = Self::ZERO;
 }
 
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> UintLike for Residue<MOD, LIMBS> {
+    fn bits_precision(&self) -> usize {
+        Uint::<LIMBS>::BITS
+    }
+
+    fn nlimbs(&self) -> usize {
+        LIMBS
+    }
+
+    fn zero_like(&self) -> Self {
+        Self::ZERO
+    }
+
+    fn one_like(&self) -> Self {
+        Self::ONE
+    }
+}
+
 #[cfg(feature = "rand_core")]
 impl<MOD, const LIMBS: usize> Random for Residue<MOD, LIMBS>
 where
@@ -249,6 +338,22 @@ where
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a, MOD, const LIMBS: usize> arbitrary::Arbitrary<'a> for Residue<MOD, LIMBS>
+where
+    MOD: ResidueParams<LIMBS>,
+{
+    /// Generate an arbitrary residue by reducing an arbitrary [`Uint`] modulo `MOD::MODULUS`.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let integer: Uint<LIMBS> = u.arbitrary()?;
+        Ok(Self::new(&integer.rem(&NonZero::from_uint(MOD::MODULUS))))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <Uint<LIMBS> as arbitrary::Arbitrary<'a>>::size_hint(depth)
+    }
+}
+
 impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Retrieve for Residue<MOD, LIMBS> {
     type Output = Uint<LIMBS>;
     fn retrieve(&self) -> Self::Output {
@@ -256,6 +361,75 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Retrieve for Residue<MOD, LI
     }
 }
 
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Monty for Residue<MOD, LIMBS> {
+    type Integer = Uint<LIMBS>;
+    type Params = PhantomData<MOD>;
+
+    fn new(integer: &Uint<LIMBS>, _params: PhantomData<MOD>) -> Self {
+        Self::new(integer)
+    }
+
+    fn zero(_params: PhantomData<MOD>) -> Self {
+        Self::ZERO
+    }
+
+    fn one(_params: PhantomData<MOD>) -> Self {
+        Self::ONE
+    }
+
+    fn params(&self) -> &PhantomData<MOD> {
+        &self.phantom
+    }
+
+    fn as_montgomery(&self) -> &Uint<LIMBS> {
+        self.as_montgomery()
+    }
+
+    fn square(&self) -> Self {
+        self.square()
+    }
+
+    fn div_by_2(&self) -> Self {
+        self.div_by_2()
+    }
+}
+
+#[cfg(all(feature = "der", feature = "generic-array"))]
+impl<'a, MOD, const LIMBS: usize> DecodeValue<'a> for Residue<MOD, LIMBS>
+where
+    MOD: ResidueParams<LIMBS>,
+    Uint<LIMBS>: ArrayEncoding,
+{
+    fn decode_value<R: der::Reader<'a>>(reader: &mut R, header: der::Header) -> der::Result<Self> {
+        let integer = Uint::decode_value(reader, header)?;
+        Option::from(Self::from_canonical(integer)).ok_or_else(|| Tag::Integer.value_error())
+    }
+}
+
+#[cfg(all(feature = "der", feature = "generic-array"))]
+impl<MOD, const LIMBS: usize> EncodeValue for Residue<MOD, LIMBS>
+where
+    MOD: ResidueParams<LIMBS>,
+    Uint<LIMBS>: ArrayEncoding,
+{
+    fn value_len(&self) -> der::Result<Length> {
+        self.retrieve().value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut impl der::Writer) -> der::Result<()> {
+        self.retrieve().encode_value(encoder)
+    }
+}
+
+#[cfg(all(feature = "der", feature = "generic-array"))]
+impl<MOD, const LIMBS: usize> FixedTag for Residue<MOD, LIMBS>
+where
+    MOD: ResidueParams<LIMBS>,
+    Uint<LIMBS>: ArrayEncoding,
+{
+    const TAG: Tag = Tag::Integer;
+}
+
 #[cfg(feature = "serde")]
 impl<'de, MOD, const LIMBS: usize> Deserialize<'de> for Residue<MOD, LIMBS>
 where
@@ -315,9 +489,11 @@ where
     }
 }
 
-#[cfg(all(test, feature = "serde"))]
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
-    use crate::{const_residue, impl_modulus, modular::constant_mod::ResidueParams, U256};
+    use super::Residue;
+    use crate::{modular::constant_mod::ResidueParams, Encoding, U256};
 
     impl_modulus!(
         Modulus,
@@ -325,6 +501,7 @@ mod tests {
         "9CC24C5DF431A864188AB905AC751B727C9447A8E99E6366E1AD78A21E8D882B"
     );
 
+    #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
         let value_uint = U256::from(105u64);
@@ -335,4 +512,73 @@ mod tests {
 
         assert_eq!(value, value_decoded);
     }
+
+    #[test]
+    fn bytes_roundtrip() {
+        let value_uint = U256::from(105u64);
+        let value = const_residue!(value_uint, Modulus);
+
+        assert_eq!(value.to_be_bytes(), value_uint.to_be_bytes());
+        assert_eq!(value.to_le_bytes(), value_uint.to_le_bytes());
+        assert_eq!(
+            Residue::from_be_bytes_canonical(value.to_be_bytes()).unwrap(),
+            value
+        );
+        assert_eq!(
+            Residue::from_le_bytes_canonical(value.to_le_bytes()).unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn bytes_non_canonical() {
+        let modulus = Modulus::MODULUS;
+        let max = modulus.wrapping_sub(&U256::ONE);
+
+        assert!(bool::from(
+            Residue::<Modulus, { U256::LIMBS }>::from_be_bytes_canonical(max.to_be_bytes())
+                .is_some()
+        ));
+        assert!(bool::from(
+            Residue::<Modulus, { U256::LIMBS }>::from_be_bytes_canonical(modulus.to_be_bytes())
+                .is_none()
+        ));
+        assert!(bool::from(
+            Residue::<Modulus, { U256::LIMBS }>::from_le_bytes_canonical(U256::MAX.to_le_bytes())
+                .is_none()
+        ));
+    }
+
+    #[cfg(all(feature = "der", feature = "generic-array"))]
+    #[test]
+    fn der_roundtrip() {
+        use der::{Decode, Encode};
+
+        let value_uint = U256::from(105u64);
+        let value = const_residue!(value_uint, Modulus);
+
+        let mut buf = [0u8; 64];
+        let encoded = value.encode_to_slice(&mut buf).unwrap();
+        assert_eq!(encoded, value_uint.encode_to_slice(&mut [0u8; 64]).unwrap());
+        assert_eq!(Residue::from_der(encoded).unwrap(), value);
+
+        let encoded = Modulus::MODULUS.encode_to_slice(&mut buf).unwrap();
+        assert!(Residue::<Modulus, { U256::LIMBS }>::from_der(encoded).is_err());
+    }
+
+    #[cfg(feature = "generic-array")]
+    #[test]
+    fn byte_array_roundtrip() {
+        let value_uint = U256::from(105u64);
+        let value = const_residue!(value_uint, Modulus);
+
+        assert_eq!(
+            Residue::from_be_byte_array_canonical(value.to_be_byte_array()).unwrap(),
+            value
+        );
+        assert_eq!(
+            Residue::from_le_byte_array_canonical(value.to_le_byte_array()).unwrap(),
+            value
+        );
+    }
 }
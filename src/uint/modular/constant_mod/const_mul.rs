@@ -4,7 +4,7 @@ use core::{
 };
 
 use crate::{
-    modular::mul::{mul_montgomery_form, square_montgomery_form},
+    modular::mul::{mul_montgomery_form, mul_montgomery_form_assign, square_montgomery_form},
     traits::Square,
 };
 
@@ -24,6 +24,16 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
         }
     }
 
+    /// Multiplies by `rhs` in place, without allocating temporaries for the product.
+    pub fn montgomery_mul_assign(&mut self, rhs: &Self) {
+        mul_montgomery_form_assign(
+            &mut self.montgomery_form,
+            &rhs.montgomery_form,
+            &MOD::MODULUS,
+            MOD::MOD_NEG_INV,
+        );
+    }
+
     /// Computes the (reduced) square of a residue.
     pub fn square(&self) -> Self {
         Self {
@@ -77,7 +87,7 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Mul<Residue<MOD, LIMBS>>
 
 impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> MulAssign<&Self> for Residue<MOD, LIMBS> {
     fn mul_assign(&mut self, rhs: &Residue<MOD, LIMBS>) {
-        *self = *self * rhs;
+        self.montgomery_mul_assign(rhs);
     }
 }
 
@@ -92,3 +102,31 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Square for Residue<MOD, LIMB
         Residue::square(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{const_residue, impl_modulus, modular::constant_mod::ResidueParams, U256};
+
+    impl_modulus!(
+        Modulus,
+        U256,
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff43"
+    );
+
+    #[test]
+    fn montgomery_mul_assign() {
+        let x =
+            U256::from_be_hex("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff42");
+        let y =
+            U256::from_be_hex("d5777c45019673125ad240f83094d4252d829516fac8601ed01979ec1ec1a251");
+        let x_mod = const_residue!(x, Modulus);
+        let y_mod = const_residue!(y, Modulus);
+
+        let mut actual = x_mod;
+        actual.montgomery_mul_assign(&y_mod);
+        assert_eq!(actual, x_mod * y_mod);
+
+        actual.montgomery_mul_assign(&x_mod);
+        assert_eq!(actual.retrieve(), y);
+    }
+}
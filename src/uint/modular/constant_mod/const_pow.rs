@@ -1,9 +1,23 @@
-use crate::{modular::pow::pow_montgomery_form, MultiExponentiateBoundedExp, PowBoundedExp, Uint};
+use crate::{
+    modular::pow::{
+        pow_montgomery_form, pow_montgomery_form_ladder, pow_montgomery_form_ladder_checked,
+        pow_montgomery_form_with_window, Window,
+    },
+    Limb, MultiExponentiateBoundedExp, PowBoundedExp, Uint,
+};
+use subtle::CtOption;
 
 use super::{Residue, ResidueParams};
 use crate::modular::pow::multi_exponentiate_montgomery_form_array;
 #[cfg(feature = "alloc")]
-use crate::modular::pow::multi_exponentiate_montgomery_form_slice;
+use crate::modular::pow::{
+    multi_exponentiate_montgomery_form_slice, multi_exponentiate_montgomery_form_vartime,
+};
+#[cfg(feature = "rand_core")]
+use crate::{
+    modular::pow::{pow_montgomery_form_ladder_blinded, pow_montgomery_form_ladder_exp_blinded},
+    rand_core::CryptoRngCore,
+};
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
@@ -35,6 +49,154 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
             phantom: core::marker::PhantomData,
         }
     }
+
+    /// Raises to the `exponent` power using a fixed window of `W::BITS` bits.
+    ///
+    /// [`Self::pow`] uses a 4-bit window, i.e. [`Window4`][`crate::modular::Window4`].
+    /// Wider windows are faster for large moduli, narrower ones use less stack for the
+    /// table of powers.
+    pub fn pow_with_window<W: Window>(&self, exponent: &impl AsRef<[Limb]>) -> Residue<MOD, LIMBS> {
+        self.pow_bounded_exp_with_window::<W>(exponent, exponent.as_ref().len() * Limb::BITS)
+    }
+
+    /// Raises to the `exponent` power using a fixed window of `W::BITS` bits,
+    /// with `exponent_bits` representing the number of (least significant) bits
+    /// to take into account for the exponent.
+    ///
+    /// NOTE: `exponent_bits` may be leaked in the time pattern.
+    pub fn pow_bounded_exp_with_window<W: Window>(
+        &self,
+        exponent: &impl AsRef<[Limb]>,
+        exponent_bits: usize,
+    ) -> Residue<MOD, LIMBS> {
+        Self {
+            montgomery_form: pow_montgomery_form_with_window::<LIMBS, W>(
+                &self.montgomery_form,
+                exponent.as_ref(),
+                exponent_bits,
+                &MOD::MODULUS,
+                &MOD::R,
+                MOD::MOD_NEG_INV,
+            ),
+            phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Raises to the `exponent` power using Montgomery's ladder.
+    ///
+    /// Unlike [`Self::pow`], every exponent bit is processed with the same sequence
+    /// of one multiplication and one squaring.
+    pub fn pow_ladder<const RHS_LIMBS: usize>(&self, exponent: &Uint<RHS_LIMBS>) -> Self {
+        self.pow_ladder_bounded_exp(exponent, Uint::<RHS_LIMBS>::BITS)
+    }
+
+    /// Raises to the `exponent` power using Montgomery's ladder,
+    /// with `exponent_bits` representing the number of (least significant) bits
+    /// to take into account for the exponent.
+    ///
+    /// NOTE: `exponent_bits` may be leaked in the time pattern.
+    pub fn pow_ladder_bounded_exp<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Uint<RHS_LIMBS>,
+        exponent_bits: usize,
+    ) -> Self {
+        let (montgomery_form, _) = pow_montgomery_form_ladder(
+            &self.montgomery_form,
+            exponent,
+            exponent_bits,
+            &MOD::MODULUS,
+            &MOD::R,
+            MOD::MOD_NEG_INV,
+        );
+        self.with_montgomery_form(montgomery_form)
+    }
+
+    /// Raises to the `exponent` power using Montgomery's ladder, and checks afterwards
+    /// that the invariant of the ladder still holds.
+    ///
+    /// Returns `None` if one of the ladder registers was corrupted during the computation.
+    /// Faults on the exponent bits or on the conditional swaps are not detected.
+    pub fn pow_ladder_checked<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Uint<RHS_LIMBS>,
+    ) -> CtOption<Self> {
+        let (montgomery_form, is_valid) = pow_montgomery_form_ladder_checked(
+            &self.montgomery_form,
+            exponent,
+            Uint::<RHS_LIMBS>::BITS,
+            &MOD::MODULUS,
+            &MOD::R,
+            MOD::MOD_NEG_INV,
+        );
+        CtOption::new(self.with_montgomery_form(montgomery_form), is_valid.into())
+    }
+
+    /// Raises to the `exponent` power using Montgomery's ladder with exponent blinding,
+    /// and checks afterwards that the invariant of the ladder still holds.
+    ///
+    /// The exponent is replaced with `exponent + k * order` for a random limb `k`.
+    /// `order` must be a multiple of the multiplicative order of `self`
+    /// (e.g. `p - 1` for a prime modulus `p`, or Euler's totient of the modulus).
+    ///
+    /// Returns `None` under the same conditions as [`Self::pow_ladder_checked`].
+    #[cfg(feature = "rand_core")]
+    pub fn pow_ladder_exp_blinded<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Uint<RHS_LIMBS>,
+        order: &Uint<RHS_LIMBS>,
+        rng: &mut impl CryptoRngCore,
+    ) -> CtOption<Self> {
+        let (montgomery_form, is_valid) = pow_montgomery_form_ladder_exp_blinded(
+            &self.montgomery_form,
+            exponent,
+            order,
+            rng,
+            &MOD::MODULUS,
+            &MOD::R,
+            MOD::MOD_NEG_INV,
+        );
+        CtOption::new(self.with_montgomery_form(montgomery_form), is_valid.into())
+    }
+
+    /// Raises to the `exponent` power using Montgomery's ladder with base and exponent blinding,
+    /// and checks afterwards that the invariant of the ladders still holds.
+    ///
+    /// The base is multiplied by a random mask `m`, and the result by `(m^-1)^exponent`,
+    /// which is computed with a second ladder, so this costs twice as much as
+    /// [`Self::pow_ladder_exp_blinded`]. Both ladders use independently blinded exponents.
+    ///
+    /// Since the mask is blinded as well, `order` must be a multiple of the order of every
+    /// invertible residue, i.e. the exponent of the multiplicative group (e.g. `p - 1` for a
+    /// prime modulus `p`, or the Carmichael function of the modulus). Passing [`Uint::ZERO`]
+    /// disables exponent blinding.
+    ///
+    /// Returns `None` under the same conditions as [`Self::pow_ladder_checked`].
+    #[cfg(feature = "rand_core")]
+    pub fn pow_ladder_blinded<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Uint<RHS_LIMBS>,
+        order: &Uint<RHS_LIMBS>,
+        rng: &mut impl CryptoRngCore,
+    ) -> CtOption<Self> {
+        let (montgomery_form, is_valid) = pow_montgomery_form_ladder_blinded(
+            &self.montgomery_form,
+            exponent,
+            order,
+            rng,
+            &MOD::MODULUS,
+            &MOD::R,
+            &MOD::R3,
+            MOD::MOD_NEG_INV,
+        );
+        CtOption::new(self.with_montgomery_form(montgomery_form), is_valid.into())
+    }
+
+    fn with_montgomery_form(&self, montgomery_form: Uint<LIMBS>) -> Self {
+        Self {
+            montgomery_form,
+            phantom: core::marker::PhantomData,
+        }
+    }
 }
 
 impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize, const RHS_LIMBS: usize>
@@ -74,6 +236,17 @@ impl<const N: usize, MOD: ResidueParams<LIMBS>, const LIMBS: usize, const RHS_LI
             phantom: core::marker::PhantomData,
         }
     }
+
+    #[cfg(feature = "alloc")]
+    fn multi_exponentiate_bounded_exp_vartime(
+        bases_and_exponents: &[(Self, Uint<RHS_LIMBS>); N],
+        exponent_bits: usize,
+    ) -> Self {
+        <Self as MultiExponentiateBoundedExp<_, [(Self, Uint<RHS_LIMBS>)]>>::multi_exponentiate_bounded_exp_vartime(
+            bases_and_exponents.as_slice(),
+            exponent_bits,
+        )
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -100,12 +273,36 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize, const RHS_LIMBS: usize>
             phantom: core::marker::PhantomData,
         }
     }
+
+    fn multi_exponentiate_bounded_exp_vartime(
+        bases_and_exponents: &[(Self, Uint<RHS_LIMBS>)],
+        exponent_bits: usize,
+    ) -> Self {
+        let bases_and_exponents: Vec<(Uint<LIMBS>, Uint<RHS_LIMBS>)> = bases_and_exponents
+            .iter()
+            .map(|(base, exp)| (base.montgomery_form, *exp))
+            .collect();
+        Self {
+            montgomery_form: multi_exponentiate_montgomery_form_vartime(
+                &bases_and_exponents,
+                exponent_bits,
+                &MOD::MODULUS,
+                &MOD::R,
+                MOD::MOD_NEG_INV,
+            ),
+            phantom: core::marker::PhantomData,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::traits::MultiExponentiate;
-    use crate::{const_residue, impl_modulus, modular::constant_mod::ResidueParams, U256};
+    use crate::{
+        const_residue, impl_modulus,
+        modular::{constant_mod::ResidueParams, Window1, Window2, Window3, Window5, Window6},
+        U256,
+    };
 
     impl_modulus!(
         Modulus,
@@ -170,6 +367,92 @@ mod tests {
         assert_eq!(res.retrieve(), expected);
     }
 
+    #[test]
+    fn test_powmod_with_window() {
+        let base =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let base_mod = const_residue!(base, Modulus);
+
+        let exponent =
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+
+        let expected =
+            U256::from_be_hex("3681BC0FEA2E5D394EB178155A127B0FD2EF405486D354251C385BDD51B9D421");
+        assert_eq!(
+            base_mod.pow_with_window::<Window1>(&exponent).retrieve(),
+            expected
+        );
+        assert_eq!(
+            base_mod.pow_with_window::<Window2>(&exponent).retrieve(),
+            expected
+        );
+        assert_eq!(
+            base_mod.pow_with_window::<Window3>(&exponent).retrieve(),
+            expected
+        );
+        assert_eq!(
+            base_mod.pow_with_window::<Window5>(&exponent).retrieve(),
+            expected
+        );
+        assert_eq!(
+            base_mod.pow_with_window::<Window6>(&exponent).retrieve(),
+            expected
+        );
+
+        for exponent_bits in [0, 1, 63, 64, 65, 100, 255] {
+            assert_eq!(
+                base_mod.pow_bounded_exp_with_window::<Window5>(&exponent, exponent_bits),
+                base_mod.pow_bounded_exp(&exponent, exponent_bits)
+            );
+            assert_eq!(
+                base_mod.pow_bounded_exp_with_window::<Window6>(&exponent, exponent_bits),
+                base_mod.pow_bounded_exp(&exponent, exponent_bits)
+            );
+        }
+    }
+
+    #[test]
+    fn test_pow_ladder() {
+        let base =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let base_mod = const_residue!(base, Modulus);
+
+        let exponent =
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+
+        let expected =
+            U256::from_be_hex("3681BC0FEA2E5D394EB178155A127B0FD2EF405486D354251C385BDD51B9D421");
+        assert_eq!(base_mod.pow_ladder(&exponent).retrieve(), expected);
+        assert!(bool::from(base_mod.pow_ladder_checked(&exponent).is_some()));
+        assert_eq!(
+            base_mod.pow_ladder_bounded_exp(&exponent, 100),
+            base_mod.pow_bounded_exp(&exponent, 100)
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_pow_ladder_blinded() {
+        use rand_core::OsRng;
+
+        let base =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let base_mod = const_residue!(base, Modulus);
+
+        let exponent =
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+
+        // Without exponent blinding, the order of the group isn't needed
+        let res = base_mod
+            .pow_ladder_blinded(&exponent, &U256::ZERO, &mut OsRng)
+            .unwrap();
+
+        let expected =
+            U256::from_be_hex("3681BC0FEA2E5D394EB178155A127B0FD2EF405486D354251C385BDD51B9D421");
+        assert_eq!(res.retrieve(), expected);
+    }
+
     #[test]
     fn test_multi_exp_array() {
         let base = U256::from(2u8);
@@ -238,4 +521,61 @@ mod tests {
 
         assert_eq!(res, expected);
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_multi_exp_vartime() {
+        use crate::traits::MultiExponentiateBoundedExp;
+        use alloc::vec::Vec;
+
+        type R = crate::modular::constant_mod::Residue<Modulus, { U256::LIMBS }>;
+
+        let exponent =
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+
+        // Enough bases to use a wider Pippenger window
+        for n in [1u64, 2, 5, 40] {
+            let bases_and_exponents: Vec<(R, U256)> = (1..=n)
+                .map(|i| {
+                    let base = U256::from(i * 7919 + 3);
+                    let exp = exponent.wrapping_mul(&U256::from(i));
+                    (const_residue!(base, Modulus), exp)
+                })
+                .collect();
+
+            let expected = bases_and_exponents
+                .iter()
+                .fold(R::ONE, |acc, (base, exp)| acc * base.pow(exp));
+
+            assert_eq!(
+                R::multi_exponentiate(bases_and_exponents.as_slice()),
+                expected
+            );
+            assert_eq!(
+                R::multi_exponentiate_vartime(bases_and_exponents.as_slice()),
+                expected
+            );
+
+            let expected = bases_and_exponents.iter().fold(R::ONE, |acc, (base, exp)| {
+                acc * base.pow_bounded_exp(exp, 131)
+            });
+            assert_eq!(
+                R::multi_exponentiate_bounded_exp(bases_and_exponents.as_slice(), 131),
+                expected
+            );
+            assert_eq!(
+                R::multi_exponentiate_bounded_exp_vartime(bases_and_exponents.as_slice(), 131),
+                expected
+            );
+        }
+
+        let bases_and_exponents = [
+            (const_residue!(exponent, Modulus), U256::from(3u8)),
+            (R::ONE + R::ONE, exponent),
+        ];
+        assert_eq!(
+            R::multi_exponentiate_vartime(&bases_and_exponents),
+            R::multi_exponentiate(&bases_and_exponents)
+        );
+    }
 }
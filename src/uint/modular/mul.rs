@@ -1,6 +1,6 @@
-use crate::{Limb, Uint};
+use crate::{limb_slice, CtChoice, Limb, Uint};
 
-use super::reduction::montgomery_reduction;
+use super::reduction::redc;
 
 #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
 use crate::risc0;
@@ -16,8 +16,7 @@ pub(crate) fn mul_montgomery_form<const LIMBS: usize>(
         return risc0::modmul_uint_256(a, b, modulus);
     }
 
-    let product = a.mul_wide(b);
-    montgomery_reduction::<LIMBS>(&product, modulus, mod_neg_inv)
+    redc(a.mul_wide(b), modulus, mod_neg_inv)
 }
 
 pub(crate) fn square_montgomery_form<const LIMBS: usize>(
@@ -30,6 +29,56 @@ pub(crate) fn square_montgomery_form<const LIMBS: usize>(
         return risc0::modmul_uint_256(a, a, modulus);
     }
 
-    let product = a.square_wide();
-    montgomery_reduction::<LIMBS>(&product, modulus, mod_neg_inv)
+    redc(a.square_wide(), modulus, mod_neg_inv)
+}
+
+/// Computes `a = a * b * R^-1 mod modulus` in place, using the
+/// Coarsely Integrated Operand Scanning method.
+///
+/// Only a single accumulator of `LIMBS` limbs (plus two extra limbs) is used,
+/// instead of the double-width product required by [`mul_montgomery_form`].
+pub(crate) fn mul_montgomery_form_assign<const LIMBS: usize>(
+    a: &mut Uint<LIMBS>,
+    b: &Uint<LIMBS>,
+    modulus: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) {
+    #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
+    if LIMBS == risc0::BIGINT_WIDTH_WORDS {
+        *a = risc0::modmul_uint_256(a, b, modulus);
+        return;
+    }
+
+    let mut acc = Uint::<LIMBS>::ZERO;
+    let mut acc_hi = Limb::ZERO;
+
+    let mut i = 0;
+    while i < LIMBS {
+        // acc += a * b[i]
+        let carry = limb_slice::addmul_1(&mut acc.limbs, &a.limbs, b.limbs[i]);
+        let (sum, acc_top) = acc_hi.adc(carry, Limb::ZERO);
+
+        // acc = (acc + u * modulus) / 2^Limb::BITS
+        let u = acc.limbs[0].wrapping_mul(mod_neg_inv);
+        let (_, mut carry) = acc.limbs[0].mac(u, modulus.limbs[0], Limb::ZERO);
+        let mut j = 1;
+        while j < LIMBS {
+            (acc.limbs[j - 1], carry) = acc.limbs[j].mac(u, modulus.limbs[j], carry);
+            j += 1;
+        }
+        let carry2;
+        (acc.limbs[LIMBS - 1], carry2) = sum.adc(carry, Limb::ZERO);
+        acc_hi = acc_top.wrapping_add(carry2);
+
+        i += 1;
+    }
+
+    // The accumulator is now less than `2 * modulus`, so `acc_hi` is either 0 or 1
+    let borrow = limb_slice::sub_n(&mut acc.limbs, &modulus.limbs, Limb::ZERO);
+
+    // Add the modulus back iff `acc_hi == 0` and the subtraction underflowed
+    let underflow = CtChoice::from_mask((!acc_hi.0.wrapping_neg()) & borrow.0);
+    limb_slice::conditional_add_n(&mut acc.limbs, &modulus.limbs, underflow);
+
+    *a = acc;
 }
@@ -1,17 +1,21 @@
-use crate::{Limb, Uint, Word};
+use crate::{CtChoice, Limb, Uint, Word};
 
 use super::mul::{mul_montgomery_form, square_montgomery_form};
 
+#[cfg(feature = "rand_core")]
+use super::inv::inv_montgomery_form;
+#[cfg(feature = "rand_core")]
+use crate::{rand_core::CryptoRngCore, NonZero, Random, RandomMod};
+
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 
 #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
 use crate::risc0;
 
 const WINDOW: usize = 4;
-const WINDOW_MASK: Word = (1 << WINDOW) - 1;
 
-/// Performs modular exponentiation using Montgomery's ladder.
+/// Performs modular exponentiation using a fixed window of 4 bits.
 /// `exponent_bits` represents the number of bits to take into account for the exponent.
 ///
 /// NOTE: this value is leaked in the time pattern.
@@ -32,6 +36,313 @@ pub fn pow_montgomery_form<const LIMBS: usize, const RHS_LIMBS: usize>(
     )
 }
 
+/// Performs modular exponentiation using a fixed window of `W::BITS` bits.
+/// `exponent_bits` represents the number of (least significant) bits of `exponent`
+/// to take into account.
+///
+/// A table of `2^W::BITS` powers of `x` is kept on the stack and scanned in constant time
+/// for every window, so larger windows trade stack space for fewer multiplications.
+///
+/// NOTE: `exponent_bits` is leaked in the time pattern.
+pub fn pow_montgomery_form_with_window<const LIMBS: usize, W: Window>(
+    x: &Uint<LIMBS>,
+    exponent: &[Limb],
+    exponent_bits: usize,
+    modulus: &Uint<LIMBS>,
+    r: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> Uint<LIMBS> {
+    #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
+    let one = if LIMBS == risc0::BIGINT_WIDTH_WORDS {
+        Uint::<LIMBS>::ONE
+    } else {
+        *r // 1 in Montgomery form
+    };
+
+    #[cfg(not(all(target_os = "zkvm", target_arch = "riscv32")))]
+    let one = *r; // 1 in Montgomery form
+
+    W::pow_montgomery_form(x, exponent, exponent_bits, modulus, &one, mod_neg_inv)
+}
+
+/// Width of the window used by the `pow_with_window` methods of
+/// [`Residue`][`super::constant_mod::Residue`] and [`DynResidue`][`super::runtime_mod::DynResidue`].
+///
+/// This trait is sealed: it is implemented by [`Window1`] to [`Window6`], each of which
+/// carries the size of its table of powers.
+pub trait Window: sealed::Sealed {
+    /// Width of the window in bits.
+    const BITS: usize;
+}
+
+mod sealed {
+    use crate::{Limb, Uint};
+
+    pub trait Sealed {
+        /// Performs modular exponentiation with a table of `2^Window::BITS` powers of `x`.
+        fn pow_montgomery_form<const LIMBS: usize>(
+            x: &Uint<LIMBS>,
+            exponent: &[Limb],
+            exponent_bits: usize,
+            modulus: &Uint<LIMBS>,
+            one: &Uint<LIMBS>,
+            mod_neg_inv: Limb,
+        ) -> Uint<LIMBS>;
+    }
+}
+
+macro_rules! impl_window {
+    ($($name:ident => $bits:expr),+) => {
+        $(
+            #[doc = concat!("A window of ", stringify!($bits), " bits, with a table of `2^", stringify!($bits), "` powers.")]
+            #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+            pub struct $name;
+
+            impl sealed::Sealed for $name {
+                fn pow_montgomery_form<const LIMBS: usize>(
+                    x: &Uint<LIMBS>,
+                    exponent: &[Limb],
+                    exponent_bits: usize,
+                    modulus: &Uint<LIMBS>,
+                    one: &Uint<LIMBS>,
+                    mod_neg_inv: Limb,
+                ) -> Uint<LIMBS> {
+                    let powers =
+                        compute_powers::<LIMBS, { 1 << $bits }>(x, modulus, one, mod_neg_inv);
+
+                    multi_exponentiate_montgomery_form_internal(
+                        &[(powers, exponent)],
+                        exponent_bits,
+                        modulus,
+                        one,
+                        mod_neg_inv,
+                    )
+                }
+            }
+
+            impl Window for $name {
+                const BITS: usize = $bits;
+            }
+        )+
+    };
+}
+
+impl_window!(
+    Window1 => 1,
+    Window2 => 2,
+    Window3 => 3,
+    Window4 => 4,
+    Window5 => 5,
+    Window6 => 6
+);
+
+/// Performs modular exponentiation using Montgomery's ladder.
+/// `exponent_bits` represents the number of bits to take into account for the exponent.
+///
+/// Every exponent bit costs exactly one multiplication and one squaring.
+/// Returns `(x^e, x^(e + 1))`: the ladder keeps their ratio equal to `x`,
+/// which can be checked afterwards with [`check_ladder_montgomery_form`].
+///
+/// NOTE: `exponent_bits` is leaked in the time pattern.
+pub fn pow_montgomery_form_ladder<const LIMBS: usize, const RHS_LIMBS: usize>(
+    x: &Uint<LIMBS>,
+    exponent: &Uint<RHS_LIMBS>,
+    exponent_bits: usize,
+    modulus: &Uint<LIMBS>,
+    r: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> (Uint<LIMBS>, Uint<LIMBS>) {
+    #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
+    let one = if LIMBS == risc0::BIGINT_WIDTH_WORDS {
+        Uint::<LIMBS>::ONE
+    } else {
+        *r // 1 in Montgomery form
+    };
+
+    #[cfg(not(all(target_os = "zkvm", target_arch = "riscv32")))]
+    let one = *r; // 1 in Montgomery form
+
+    ladder_montgomery_form(
+        (one, *x),
+        exponent.as_limbs(),
+        exponent_bits,
+        modulus,
+        mod_neg_inv,
+    )
+}
+
+/// Performs modular exponentiation using Montgomery's ladder, and checks afterwards
+/// that the invariant of the ladder still holds.
+///
+/// Returns `x^e` along with the truthy value if the check passed.
+/// See [`check_ladder_montgomery_form`] for the faults this detects.
+///
+/// NOTE: `exponent_bits` is leaked in the time pattern.
+pub fn pow_montgomery_form_ladder_checked<const LIMBS: usize, const RHS_LIMBS: usize>(
+    x: &Uint<LIMBS>,
+    exponent: &Uint<RHS_LIMBS>,
+    exponent_bits: usize,
+    modulus: &Uint<LIMBS>,
+    r: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> (Uint<LIMBS>, CtChoice) {
+    let state = pow_montgomery_form_ladder(x, exponent, exponent_bits, modulus, r, mod_neg_inv);
+    let is_valid = check_ladder_montgomery_form(x, &state, modulus, mod_neg_inv);
+    (state.0, is_valid)
+}
+
+/// Performs modular exponentiation using Montgomery's ladder with a blinded exponent,
+/// and checks afterwards that the invariant of the ladder still holds.
+///
+/// The exponent is replaced with `exponent + k * order` for a random limb `k`, which gives the
+/// same result as long as `order` is a multiple of the multiplicative order of `x`, but
+/// randomizes the sequence of exponent bits processed by the ladder.
+///
+/// Returns `x^e` along with the truthy value if the check passed.
+#[cfg(feature = "rand_core")]
+pub fn pow_montgomery_form_ladder_exp_blinded<const LIMBS: usize, const RHS_LIMBS: usize>(
+    x: &Uint<LIMBS>,
+    exponent: &Uint<RHS_LIMBS>,
+    order: &Uint<RHS_LIMBS>,
+    rng: &mut impl CryptoRngCore,
+    modulus: &Uint<LIMBS>,
+    r: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> (Uint<LIMBS>, CtChoice) {
+    let k = Limb::random(rng);
+
+    // `exponent + k * order` doesn't fit in `RHS_LIMBS` limbs, so the top limb is kept apart
+    let mut blinded = [Limb::ZERO; RHS_LIMBS];
+    let mut carry = Limb::ZERO;
+    let mut i = 0;
+    while i < RHS_LIMBS {
+        (blinded[i], carry) = exponent.as_limbs()[i].mac(order.as_limbs()[i], k, carry);
+        i += 1;
+    }
+
+    let state = pow_montgomery_form_ladder(
+        x,
+        &Uint::<1>::from_word(carry.0),
+        Limb::BITS,
+        modulus,
+        r,
+        mod_neg_inv,
+    );
+    let state = ladder_montgomery_form(
+        state,
+        &blinded,
+        Uint::<RHS_LIMBS>::BITS,
+        modulus,
+        mod_neg_inv,
+    );
+    let is_valid = check_ladder_montgomery_form(x, &state, modulus, mod_neg_inv);
+    (state.0, is_valid)
+}
+
+/// Performs modular exponentiation using Montgomery's ladder with base and exponent blinding,
+/// and checks afterwards that the invariant of the ladders still holds.
+///
+/// The base is multiplied by a random mask `m`, and the result by `(m^-1)^e`. Both powers are
+/// computed with [`pow_montgomery_form_ladder_exp_blinded`] using independent blinding factors,
+/// so this costs two ladders. Since the mask is blinded with `order` as well, `order` must be
+/// a multiple of the order of every invertible residue, i.e. of the exponent of the
+/// multiplicative group. Zero disables exponent blinding.
+///
+/// Returns `x^e` along with the truthy value if the checks passed.
+#[cfg(feature = "rand_core")]
+#[allow(clippy::too_many_arguments)]
+pub fn pow_montgomery_form_ladder_blinded<const LIMBS: usize, const RHS_LIMBS: usize>(
+    x: &Uint<LIMBS>,
+    exponent: &Uint<RHS_LIMBS>,
+    order: &Uint<RHS_LIMBS>,
+    rng: &mut impl CryptoRngCore,
+    modulus: &Uint<LIMBS>,
+    r: &Uint<LIMBS>,
+    r3: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> (Uint<LIMBS>, CtChoice) {
+    // The mask is independent of the inputs, so it can be resampled in variable time.
+    // A uniformly random residue is also uniformly random in Montgomery form.
+    let (mask, mask_inv) = loop {
+        let mask = Uint::random_mod(rng, &NonZero::from_uint(*modulus));
+        let (mask_inv, is_invertible) = inv_montgomery_form(&mask, modulus, r3, mod_neg_inv);
+        if is_invertible.is_true_vartime() {
+            break (mask, mask_inv);
+        }
+    };
+    let blinded = mul_montgomery_form(x, &mask, modulus, mod_neg_inv);
+
+    // (x * m)^e * (m^-1)^e = x^e
+    let (r0, r0_is_valid) = pow_montgomery_form_ladder_exp_blinded(
+        &blinded,
+        exponent,
+        order,
+        rng,
+        modulus,
+        r,
+        mod_neg_inv,
+    );
+    let (s0, s0_is_valid) = pow_montgomery_form_ladder_exp_blinded(
+        &mask_inv,
+        exponent,
+        order,
+        rng,
+        modulus,
+        r,
+        mod_neg_inv,
+    );
+    (
+        mul_montgomery_form(&r0, &s0, modulus, mod_neg_inv),
+        r0_is_valid.and(s0_is_valid),
+    )
+}
+
+/// Checks that the final state `(x^e, x^(e + 1))` of Montgomery's ladder is consistent,
+/// returning the truthy value if it is.
+///
+/// This detects faults corrupting one of the registers during the computation. Faults on
+/// the exponent bits or on the conditional swaps keep both registers consistent, and are
+/// therefore not detected.
+pub fn check_ladder_montgomery_form<const LIMBS: usize>(
+    x: &Uint<LIMBS>,
+    state: &(Uint<LIMBS>, Uint<LIMBS>),
+    modulus: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> CtChoice {
+    let (r0, r1) = state;
+    Uint::ct_eq(&mul_montgomery_form(r0, x, modulus, mod_neg_inv), r1)
+}
+
+/// Continues Montgomery's ladder from the state `(x^a, x^(a + 1))`,
+/// consuming the `exponent_bits` least significant bits of `exponent`, most significant first.
+///
+/// Returns `(x^b, x^(b + 1))` where `b = a * 2^exponent_bits + exponent`.
+pub fn ladder_montgomery_form<const LIMBS: usize>(
+    state: (Uint<LIMBS>, Uint<LIMBS>),
+    exponent: &[Limb],
+    exponent_bits: usize,
+    modulus: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> (Uint<LIMBS>, Uint<LIMBS>) {
+    let (mut r0, mut r1) = state;
+
+    let mut i = exponent_bits;
+    while i > 0 {
+        i -= 1;
+
+        let bit = (exponent[i / Limb::BITS].0 >> (i % Limb::BITS)) & 1;
+        let choice = CtChoice::from_lsb(bit);
+
+        // Swap so that the value to be squared is always in `r0`
+        (r0, r1) = Uint::ct_swap(&r0, &r1, choice);
+        r1 = mul_montgomery_form(&r0, &r1, modulus, mod_neg_inv);
+        r0 = square_montgomery_form(&r0, modulus, mod_neg_inv);
+        (r0, r1) = Uint::ct_swap(&r0, &r1, choice);
+    }
+
+    (r0, r1)
+}
+
 pub fn multi_exponentiate_montgomery_form_array<
     const LIMBS: usize,
     const RHS_LIMBS: usize,
@@ -119,81 +430,195 @@ pub fn multi_exponentiate_montgomery_form_slice<const LIMBS: usize, const RHS_LI
     )
 }
 
-fn compute_powers<const LIMBS: usize>(
+/// Performs modular multi-exponentiation using Pippenger's bucket method.
+/// `exponent_bits` represents the number of bits to take into account for the exponent.
+///
+/// The window width is chosen from the number of bases, so this scales much better than the
+/// constant-time variants when many bases are combined (e.g. in batch verification).
+///
+/// See: Pippenger, N. On the evaluation of powers and monomials. SIAM Journal on Computing 9 (1980), 230–250.
+///
+/// NOTE: this function is variable time with respect to the exponents.
+#[cfg(feature = "alloc")]
+pub fn multi_exponentiate_montgomery_form_vartime<const LIMBS: usize, const RHS_LIMBS: usize>(
+    bases_and_exponents: &[(Uint<LIMBS>, Uint<RHS_LIMBS>)],
+    exponent_bits: usize,
+    modulus: &Uint<LIMBS>,
+    r: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> Uint<LIMBS> {
+    #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
+    let one = if LIMBS == risc0::BIGINT_WIDTH_WORDS {
+        Uint::<LIMBS>::ONE
+    } else {
+        *r // 1 in Montgomery form
+    };
+
+    #[cfg(not(all(target_os = "zkvm", target_arch = "riscv32")))]
+    let one = *r; // 1 in Montgomery form
+
+    if exponent_bits == 0 {
+        return one;
+    }
+
+    // Products which are still equal to one are tracked as `None`,
+    // which saves the multiplications by one.
+    let mul = |a: Option<Uint<LIMBS>>, b: &Uint<LIMBS>| match a {
+        Some(a) => mul_montgomery_form(&a, b, modulus, mod_neg_inv),
+        None => *b,
+    };
+
+    let window = pippenger_window_size(bases_and_exponents.len());
+    let mut buckets: Vec<Option<Uint<LIMBS>>> = vec![None; (1 << window) - 1];
+
+    let num_windows = (exponent_bits + window - 1) / window;
+    let mut z: Option<Uint<LIMBS>> = None;
+
+    let mut window_num = num_windows;
+    while window_num > 0 {
+        window_num -= 1;
+
+        if let Some(mut acc) = z {
+            for _ in 0..window {
+                acc = square_montgomery_form(&acc, modulus, mod_neg_inv);
+            }
+            z = Some(acc);
+        }
+
+        let pos = window_num * window;
+        let width = core::cmp::min(window, exponent_bits - pos);
+
+        buckets.iter_mut().for_each(|bucket| *bucket = None);
+        for (base, exponent) in bases_and_exponents {
+            let idx = exponent_window(exponent.as_limbs(), pos, width) as usize;
+            if idx != 0 {
+                buckets[idx - 1] = Some(mul(buckets[idx - 1], base));
+            }
+        }
+
+        // Computes `prod_k bucket[k]^k` using running products, from the highest bucket down
+        let mut running: Option<Uint<LIMBS>> = None;
+        let mut window_product: Option<Uint<LIMBS>> = None;
+        for bucket in buckets.iter().rev() {
+            if let Some(bucket) = bucket {
+                running = Some(mul(running, bucket));
+            }
+            if let Some(running) = &running {
+                window_product = Some(mul(window_product, running));
+            }
+        }
+
+        if let Some(window_product) = &window_product {
+            z = Some(mul(z, window_product));
+        }
+    }
+
+    z.unwrap_or(one)
+}
+
+/// Returns the `width` bits of `exponent` starting at bit `pos`.
+///
+/// Only the positions are leaked in the time pattern, not the exponent.
+fn exponent_window(limbs: &[Limb], pos: usize, width: usize) -> Word {
+    let limb_num = pos / Limb::BITS;
+    let bit = pos % Limb::BITS;
+
+    let mut w = limbs[limb_num].0 >> bit;
+    if bit + width > Limb::BITS && limb_num + 1 < limbs.len() {
+        w |= limbs[limb_num + 1].0 << (Limb::BITS - bit);
+    }
+
+    w & ((1 << width) - 1)
+}
+
+/// Window width for Pippenger's multi-exponentiation: roughly `ln(n) + 2` for `n` bases.
+#[cfg(feature = "alloc")]
+const fn pippenger_window_size(num_bases: usize) -> usize {
+    if num_bases < 32 {
+        3
+    } else {
+        // `log2(n) * ln(2)`, without resorting to floats
+        let log2 = (usize::BITS - num_bases.leading_zeros()) as usize;
+        let window = log2 * 69 / 100 + 2;
+        if window > 16 {
+            16
+        } else {
+            window
+        }
+    }
+}
+
+fn compute_powers<const LIMBS: usize, const TABLE: usize>(
     x: &Uint<LIMBS>,
     modulus: &Uint<LIMBS>,
     one: &Uint<LIMBS>,
     mod_neg_inv: Limb,
-) -> [Uint<LIMBS>; 1 << WINDOW] {
+) -> [Uint<LIMBS>; TABLE] {
     // powers[i] contains x^i
-    let mut powers = [*one; 1 << WINDOW];
+    let mut powers = [*one; TABLE];
     powers[1] = *x;
 
+    // Even powers are squares of the smaller ones, which are cheaper than multiplications
     let mut i = 2;
     while i < powers.len() {
-        powers[i] = mul_montgomery_form(&powers[i - 1], x, modulus, mod_neg_inv);
+        powers[i] = if i % 2 == 0 {
+            square_montgomery_form(&powers[i / 2], modulus, mod_neg_inv)
+        } else {
+            mul_montgomery_form(&powers[i - 1], x, modulus, mod_neg_inv)
+        };
         i += 1;
     }
 
     powers
 }
 
-fn multi_exponentiate_montgomery_form_internal<const LIMBS: usize, const RHS_LIMBS: usize>(
-    powers_and_exponents: &[([Uint<LIMBS>; 1 << WINDOW], Uint<RHS_LIMBS>)],
+fn multi_exponentiate_montgomery_form_internal<
+    const LIMBS: usize,
+    const TABLE: usize,
+    E: AsRef<[Limb]>,
+>(
+    powers_and_exponents: &[([Uint<LIMBS>; TABLE], E)],
     exponent_bits: usize,
     modulus: &Uint<LIMBS>,
     one: &Uint<LIMBS>,
     mod_neg_inv: Limb,
 ) -> Uint<LIMBS> {
-    let starting_limb = (exponent_bits - 1) / Limb::BITS;
-    let starting_bit_in_limb = (exponent_bits - 1) % Limb::BITS;
-    let starting_window = starting_bit_in_limb / WINDOW;
-    let starting_window_mask = (1 << (starting_bit_in_limb % WINDOW + 1)) - 1;
+    let window = TABLE.trailing_zeros() as usize;
+    let num_windows = (exponent_bits + window - 1) / window;
 
     let mut z = *one;
 
-    let mut limb_num = starting_limb + 1;
-    while limb_num > 0 {
-        limb_num -= 1;
-
-        let mut window_num = if limb_num == starting_limb {
-            starting_window + 1
-        } else {
-            Limb::BITS / WINDOW
-        };
-        while window_num > 0 {
-            window_num -= 1;
-
-            if limb_num != starting_limb || window_num != starting_window {
-                let mut i = 0;
-                while i < WINDOW {
-                    i += 1;
-                    z = square_montgomery_form(&z, modulus, mod_neg_inv);
-                }
-            }
+    let mut window_num = num_windows;
+    while window_num > 0 {
+        window_num -= 1;
 
+        if window_num != num_windows - 1 {
             let mut i = 0;
-            while i < powers_and_exponents.len() {
-                let (powers, exponent) = powers_and_exponents[i];
-                let w = exponent.as_limbs()[limb_num].0;
-                let mut idx = (w >> (window_num * WINDOW)) & WINDOW_MASK;
+            while i < window {
+                i += 1;
+                z = square_montgomery_form(&z, modulus, mod_neg_inv);
+            }
+        }
 
-                if limb_num == starting_limb && window_num == starting_window {
-                    idx &= starting_window_mask;
-                }
+        let pos = window_num * window;
+        let width = core::cmp::min(window, exponent_bits - pos);
 
-                // Constant-time lookup in the array of powers
-                let mut power = powers[0];
-                let mut j = 1;
-                while j < 1 << WINDOW {
-                    let choice = Limb::ct_eq(Limb(j as Word), Limb(idx));
-                    power = Uint::<LIMBS>::ct_select(&power, &powers[j], choice);
-                    j += 1;
-                }
+        let mut i = 0;
+        while i < powers_and_exponents.len() {
+            let (powers, exponent) = &powers_and_exponents[i];
+            let idx = exponent_window(exponent.as_ref(), pos, width);
 
-                z = mul_montgomery_form(&z, &power, modulus, mod_neg_inv);
-                i += 1;
+            // Constant-time lookup in the array of powers
+            let mut power = powers[0];
+            let mut j = 1;
+            while j < TABLE {
+                let choice = Limb::ct_eq(Limb(j as Word), Limb(idx));
+                power = Uint::<LIMBS>::ct_select(&power, &powers[j], choice);
+                j += 1;
             }
+
+            z = mul_montgomery_form(&z, &power, modulus, mod_neg_inv);
+            i += 1;
         }
     }
 
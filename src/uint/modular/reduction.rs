@@ -1,4 +1,4 @@
-use crate::{Limb, Uint, WideWord, Word};
+use crate::{limb_slice, Limb, Uint, WideWord, Word};
 
 /// Returns `(hi, lo)` such that `hi * R + lo = x * y + z + w`.
 #[inline(always)]
@@ -11,6 +11,10 @@ const fn muladdcarry(x: Word, y: Word, z: Word, w: Word) -> (Word, Word) {
 }
 
 /// Algorithm 14.32 in Handbook of Applied Cryptography <https://cacr.uwaterloo.ca/hac/about/chap14.pdf>
+///
+/// This is the `const fn` counterpart of [`limb_slice::redc`], needed to compute the
+/// constants of [`impl_modulus!`][`crate::impl_modulus`] at compile time. Outside of `const`
+/// contexts the Montgomery arithmetic of this crate goes through [`limb_slice::redc`].
 pub const fn montgomery_reduction<const LIMBS: usize>(
     lower_upper: &(Uint<LIMBS>, Uint<LIMBS>),
     modulus: &Uint<LIMBS>,
@@ -53,3 +57,20 @@ pub const fn montgomery_reduction<const LIMBS: usize>(
 
     upper.sub_mod_with_carry(meta_carry, modulus, modulus)
 }
+
+/// Montgomery reduction of `lower_upper` via [`limb_slice::redc`].
+#[inline]
+pub(crate) fn redc<const LIMBS: usize>(
+    lower_upper: (Uint<LIMBS>, Uint<LIMBS>),
+    modulus: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> Uint<LIMBS> {
+    let (mut lower, mut upper) = lower_upper;
+    limb_slice::redc(
+        &mut lower.limbs,
+        &mut upper.limbs,
+        &modulus.limbs,
+        mod_neg_inv,
+    );
+    upper
+}
@@ -1,14 +1,27 @@
-use crate::{Limb, Uint, Word};
+use crate::{Encoding, Limb, Uint, UintLike, Word};
 
 use super::{
     constant_mod::{Residue, ResidueParams},
     div_by_2::div_by_2,
-    reduction::montgomery_reduction,
-    Retrieve,
+    reduction::redc,
+    Monty, Retrieve,
 };
 
+use core::fmt;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
+#[cfg(feature = "rand_core")]
+use crate::{rand_core::CryptoRngCore, NonZero, RandomMod};
+
+#[cfg(feature = "generic-array")]
+use crate::{ArrayEncoding, ByteArray};
+
+#[cfg(feature = "serde")]
+use {
+    serdect::serde::de::Error,
+    serdect::serde::{Deserialize, Deserializer, Serialize, Serializer},
+};
+
 #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
 use crate::risc0;
 
@@ -54,7 +67,7 @@ impl<const LIMBS: usize> DynResidueParams<LIMBS> {
             Word::MIN.wrapping_sub(modulus_lo.inv_mod2k_vartime(Word::BITS as usize).limbs[0].0),
         );
 
-        let r3 = montgomery_reduction(&r2.square_wide(), modulus, mod_neg_inv);
+        let r3 = redc(r2.square_wide(), modulus, mod_neg_inv);
 
         Self {
             modulus: *modulus,
@@ -129,6 +142,68 @@ impl<const LIMBS: usize> ConstantTimeEq for DynResidueParams<LIMBS> {
     }
 }
 
+impl<const LIMBS: usize> fmt::Display for DynResidueParams<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.modulus, f)
+    }
+}
+
+impl<const LIMBS: usize> fmt::LowerHex for DynResidueParams<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.modulus, f)
+    }
+}
+
+impl<const LIMBS: usize> fmt::UpperHex for DynResidueParams<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.modulus, f)
+    }
+}
+
+/// The parameters are serialized as their modulus only, and recomputed on deserialization.
+#[cfg(feature = "serde")]
+impl<'de, const LIMBS: usize> Deserialize<'de> for DynResidueParams<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let modulus = Uint::<LIMBS>::deserialize(deserializer)?;
+
+        if modulus.ct_is_odd().into() {
+            Ok(Self::generate_params(&modulus))
+        } else {
+            Err(D::Error::custom("modulus must be odd"))
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const LIMBS: usize> Serialize for DynResidueParams<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.modulus.serialize(serializer)
+    }
+}
+
+/// Error returned when an operation is attempted between [`DynResidue`]s
+/// which were created with different [`DynResidueParams`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParamsMismatchError;
+
+impl fmt::Display for ParamsMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("residues have different parameters")
+    }
+}
+
 /// A residue represented using `LIMBS` limbs. The odd modulus of this residue is set at runtime.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DynResidue<const LIMBS: usize> {
@@ -153,9 +228,8 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
             };
         }
 
-        let product = integer.mul_wide(&residue_params.r2);
-        let montgomery_form = montgomery_reduction(
-            &product,
+        let montgomery_form = redc(
+            integer.mul_wide(&residue_params.r2),
             &residue_params.modulus,
             residue_params.mod_neg_inv,
         );
@@ -174,8 +248,8 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
             return self.montgomery_form;
         }
 
-        montgomery_reduction(
-            &(self.montgomery_form, Uint::ZERO),
+        redc(
+            (self.montgomery_form, Uint::ZERO),
             &self.residue_params.modulus,
             self.residue_params.mod_neg_inv,
         )
@@ -236,6 +310,29 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
         self.montgomery_form
     }
 
+    /// Generates a uniformly random residue with the given parameters.
+    #[cfg(feature = "rand_core")]
+    pub fn random(rng: &mut impl CryptoRngCore, residue_params: DynResidueParams<LIMBS>) -> Self {
+        let modulus = NonZero::from_uint(residue_params.modulus);
+        Self::new(&Uint::random_mod(rng, &modulus), residue_params)
+    }
+
+    /// Checks that `self` and `rhs` share the same parameters before an operation between them.
+    ///
+    /// This is a debug assertion, unless the `strict-residue-params` feature is enabled,
+    /// in which case the parameters are compared in constant time in release builds too.
+    #[inline]
+    pub(crate) fn assert_same_params(&self, rhs: &Self) {
+        #[cfg(feature = "strict-residue-params")]
+        assert!(
+            bool::from(self.residue_params.ct_eq(&rhs.residue_params)),
+            "residues have different parameters"
+        );
+
+        #[cfg(not(feature = "strict-residue-params"))]
+        debug_assert_eq!(self.residue_params, rhs.residue_params);
+    }
+
     /// Performs the modular division by 2, that is for given `x` returns `y`
     /// such that `y * 2 = x mod p`. This means:
     /// - if `x` is even, returns `x / 2`,
@@ -249,6 +346,103 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
     }
 }
 
+impl<const LIMBS: usize> DynResidue<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    /// Encodes the canonical (non-Montgomery) value of this residue as big endian bytes.
+    pub fn to_be_bytes(&self) -> <Uint<LIMBS> as Encoding>::Repr {
+        self.retrieve().to_be_bytes()
+    }
+
+    /// Encodes the canonical (non-Montgomery) value of this residue as little endian bytes.
+    pub fn to_le_bytes(&self) -> <Uint<LIMBS> as Encoding>::Repr {
+        self.retrieve().to_le_bytes()
+    }
+
+    /// Decodes a residue from the big endian bytes of its canonical value.
+    ///
+    /// Returns `None` if the value is not less than the modulus. The check is constant-time.
+    pub fn from_be_bytes_canonical(
+        bytes: <Uint<LIMBS> as Encoding>::Repr,
+        residue_params: DynResidueParams<LIMBS>,
+    ) -> CtOption<Self> {
+        Self::from_canonical(Uint::from_be_bytes(bytes), residue_params)
+    }
+
+    /// Decodes a residue from the little endian bytes of its canonical value.
+    ///
+    /// Returns `None` if the value is not less than the modulus. The check is constant-time.
+    pub fn from_le_bytes_canonical(
+        bytes: <Uint<LIMBS> as Encoding>::Repr,
+        residue_params: DynResidueParams<LIMBS>,
+    ) -> CtOption<Self> {
+        Self::from_canonical(Uint::from_le_bytes(bytes), residue_params)
+    }
+
+    fn from_canonical(
+        integer: Uint<LIMBS>,
+        residue_params: DynResidueParams<LIMBS>,
+    ) -> CtOption<Self> {
+        let is_reduced = Uint::ct_lt(&integer, &residue_params.modulus);
+        CtOption::new(Self::new(&integer, residue_params), is_reduced.into())
+    }
+}
+
+#[cfg(feature = "generic-array")]
+impl<const LIMBS: usize> DynResidue<LIMBS>
+where
+    Uint<LIMBS>: ArrayEncoding,
+{
+    /// Encodes the canonical (non-Montgomery) value of this residue as a big endian byte array.
+    pub fn to_be_byte_array(&self) -> ByteArray<Uint<LIMBS>> {
+        self.retrieve().to_be_byte_array()
+    }
+
+    /// Encodes the canonical (non-Montgomery) value of this residue as a little endian byte array.
+    pub fn to_le_byte_array(&self) -> ByteArray<Uint<LIMBS>> {
+        self.retrieve().to_le_byte_array()
+    }
+
+    /// Decodes a residue from the big endian byte array of its canonical value.
+    ///
+    /// Returns `None` if the value is not less than the modulus. The check is constant-time.
+    pub fn from_be_byte_array_canonical(
+        bytes: ByteArray<Uint<LIMBS>>,
+        residue_params: DynResidueParams<LIMBS>,
+    ) -> CtOption<Self> {
+        Self::from_canonical(Uint::from_be_byte_array(bytes), residue_params)
+    }
+
+    /// Decodes a residue from the little endian byte array of its canonical value.
+    ///
+    /// Returns `None` if the value is not less than the modulus. The check is constant-time.
+    pub fn from_le_byte_array_canonical(
+        bytes: ByteArray<Uint<LIMBS>>,
+        residue_params: DynResidueParams<LIMBS>,
+    ) -> CtOption<Self> {
+        Self::from_canonical(Uint::from_le_byte_array(bytes), residue_params)
+    }
+}
+
+impl<const LIMBS: usize> UintLike for DynResidue<LIMBS> {
+    fn bits_precision(&self) -> usize {
+        Uint::<LIMBS>::BITS
+    }
+
+    fn nlimbs(&self) -> usize {
+        LIMBS
+    }
+
+    fn zero_like(&self) -> Self {
+        Self::zero(self.residue_params)
+    }
+
+    fn one_like(&self) -> Self {
+        Self::one(self.residue_params)
+    }
+}
+
 impl<const LIMBS: usize> Retrieve for DynResidue<LIMBS> {
     type Output = Uint<LIMBS>;
     fn retrieve(&self) -> Self::Output {
@@ -256,6 +450,39 @@ impl<const LIMBS: usize> Retrieve for DynResidue<LIMBS> {
     }
 }
 
+impl<const LIMBS: usize> Monty for DynResidue<LIMBS> {
+    type Integer = Uint<LIMBS>;
+    type Params = DynResidueParams<LIMBS>;
+
+    fn new(integer: &Uint<LIMBS>, params: DynResidueParams<LIMBS>) -> Self {
+        Self::new(integer, params)
+    }
+
+    fn zero(params: DynResidueParams<LIMBS>) -> Self {
+        Self::zero(params)
+    }
+
+    fn one(params: DynResidueParams<LIMBS>) -> Self {
+        Self::one(params)
+    }
+
+    fn params(&self) -> &DynResidueParams<LIMBS> {
+        self.params()
+    }
+
+    fn as_montgomery(&self) -> &Uint<LIMBS> {
+        self.as_montgomery()
+    }
+
+    fn square(&self) -> Self {
+        self.square()
+    }
+
+    fn div_by_2(&self) -> Self {
+        self.div_by_2()
+    }
+}
+
 impl<const LIMBS: usize, P: ResidueParams<LIMBS>> From<&Residue<P, LIMBS>> for DynResidue<LIMBS> {
     fn from(residue: &Residue<P, LIMBS>) -> Self {
         Self {
@@ -289,6 +516,58 @@ impl<const LIMBS: usize> ConstantTimeEq for DynResidue<LIMBS> {
     }
 }
 
+impl<const LIMBS: usize> fmt::Display for DynResidue<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.retrieve(), f)
+    }
+}
+
+impl<const LIMBS: usize> fmt::LowerHex for DynResidue<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.retrieve(), f)
+    }
+}
+
+impl<const LIMBS: usize> fmt::UpperHex for DynResidue<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.retrieve(), f)
+    }
+}
+
+/// The residue is serialized in canonical (non-Montgomery) form, followed by its parameters.
+#[cfg(feature = "serde")]
+impl<'de, const LIMBS: usize> Deserialize<'de> for DynResidue<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (value, residue_params) =
+            <(Uint<LIMBS>, DynResidueParams<LIMBS>)>::deserialize(deserializer)?;
+
+        if Uint::ct_lt(&value, &residue_params.modulus).into() {
+            Ok(Self::new(&value, residue_params))
+        } else {
+            Err(D::Error::custom("value must be reduced"))
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const LIMBS: usize> Serialize for DynResidue<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (self.retrieve(), self.residue_params).serialize(serializer)
+    }
+}
+
 /// NOTE: this does _not_ zeroize the parameters, in order to maintain some form of type consistency
 #[cfg(feature = "zeroize")]
 impl<const LIMBS: usize> zeroize::Zeroize for DynResidue<LIMBS> {
@@ -300,6 +579,7 @@ impl<const LIMBS: usize> zeroize::Zeroize for DynResidue<LIMBS> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::U256;
 
     const LIMBS: usize = nlimbs!(64);
 
@@ -328,4 +608,193 @@ mod test {
     fn test_invalid_modulus() {
         DynResidueParams::<LIMBS>::new(&Uint::from(2u8));
     }
+
+    #[test]
+    #[cfg(feature = "strict-residue-params")]
+    #[should_panic(expected = "residues have different parameters")]
+    // Test that operations between residues with different parameters panic
+    fn test_strict_params_mismatch() {
+        let params1 = DynResidueParams::<LIMBS>::new(&Uint::from(3u8));
+        let params2 = DynResidueParams::<LIMBS>::new(&Uint::from(5u8));
+
+        let _ = DynResidue::one(params1) + DynResidue::one(params2);
+    }
+
+    #[test]
+    // Test that the checked operations detect residues with different parameters
+    fn checked_ops_params_mismatch() {
+        type Residue = DynResidue<{ U256::LIMBS }>;
+        type CheckedOp = fn(&Residue, &Residue) -> CtOption<Residue>;
+        type TryOp = fn(&Residue, &Residue) -> Result<Residue, ParamsMismatchError>;
+        type Op = fn(&Residue, &Residue) -> Residue;
+
+        let params1 = DynResidueParams::new(&U256::from_u64(0xffff_ffff_0000_0001));
+        let params2 = DynResidueParams::new(&U256::from_u64(0xffff_ffff_0000_0003));
+
+        let x = DynResidue::new(&U256::from_u8(3), params1);
+        let y = DynResidue::new(&U256::from_u8(2), params1);
+        let z = DynResidue::new(&U256::from_u8(2), params2);
+
+        // `pow` raises to the retrieved value of `rhs`, checked against the parameters of `rhs`
+        let ops: [(&str, CheckedOp, TryOp, Op); 4] = [
+            (
+                "add",
+                |a, b| a.checked_add(b),
+                |a, b| a.try_add(b),
+                |a, b| a + b,
+            ),
+            (
+                "sub",
+                |a, b| a.checked_sub(b),
+                |a, b| a.try_sub(b),
+                |a, b| a - b,
+            ),
+            (
+                "mul",
+                |a, b| a.checked_mul(b),
+                |a, b| a.try_mul(b),
+                |a, b| a * b,
+            ),
+            (
+                "pow",
+                |a, b| a.checked_pow(&b.retrieve(), b.params()),
+                |a, b| a.try_pow(&b.retrieve(), b.params()),
+                |a, b| a.pow(&b.retrieve()),
+            ),
+        ];
+
+        for (name, checked_op, try_op, op) in ops {
+            assert_eq!(checked_op(&x, &y).unwrap(), op(&x, &y), "{}", name);
+            assert!(bool::from(checked_op(&x, &z).is_none()), "{}", name);
+            assert_eq!(try_op(&x, &y), Ok(op(&x, &y)), "{}", name);
+            assert_eq!(try_op(&x, &z), Err(ParamsMismatchError), "{}", name);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn display() {
+        use alloc::format;
+
+        let params = DynResidueParams::<LIMBS>::new(&Uint::from(0xffu8));
+        let x = DynResidue::new(&Uint::from(0xabu8), params);
+
+        assert_eq!(format!("{x}"), format!("{}", Uint::<LIMBS>::from(0xabu8)));
+        assert_eq!(
+            format!("{x:x}"),
+            format!("{:x}", Uint::<LIMBS>::from(0xabu8))
+        );
+        assert_eq!(
+            format!("{x:X}"),
+            format!("{:X}", Uint::<LIMBS>::from(0xabu8))
+        );
+        assert_eq!(
+            format!("{params:x}"),
+            format!("{:x}", Uint::<LIMBS>::from(0xffu8))
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random() {
+        let params = DynResidueParams::<LIMBS>::new(&Uint::from(0xffu8));
+        for _ in 0..16 {
+            let x = DynResidue::random(&mut rand_core::OsRng, params);
+            assert!(x.retrieve() < Uint::from(0xffu8));
+            assert_eq!(x.params(), &params);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn serde_roundtrip() {
+        let params = DynResidueParams::new(&U256::from_be_hex(
+            "9CC24C5DF431A864188AB905AC751B727C9447A8E99E6366E1AD78A21E8D882B",
+        ));
+        let value = DynResidue::new(&U256::from(105u64), params);
+
+        let params_encoded = bincode::serialize(&params).unwrap();
+        assert_eq!(
+            params_encoded,
+            bincode::serialize(params.modulus()).unwrap()
+        );
+        let params_decoded: DynResidueParams<{ U256::LIMBS }> =
+            bincode::deserialize(&params_encoded).unwrap();
+        assert_eq!(params, params_decoded);
+
+        let value_encoded = bincode::serialize(&value).unwrap();
+        let value_decoded: DynResidue<{ U256::LIMBS }> =
+            bincode::deserialize(&value_encoded).unwrap();
+        assert_eq!(value, value_decoded);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn serde_invalid() {
+        let even_modulus = bincode::serialize(&U256::from(4u8)).unwrap();
+        assert!(bincode::deserialize::<DynResidueParams<{ U256::LIMBS }>>(&even_modulus).is_err());
+
+        let modulus = U256::from(5u8);
+        let unreduced = bincode::serialize(&(U256::from(5u8), modulus)).unwrap();
+        assert!(bincode::deserialize::<DynResidue<{ U256::LIMBS }>>(&unreduced).is_err());
+
+        let reduced = bincode::serialize(&(U256::from(4u8), modulus)).unwrap();
+        let value: DynResidue<{ U256::LIMBS }> = bincode::deserialize(&reduced).unwrap();
+        assert_eq!(value.retrieve(), U256::from(4u8));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn bytes_roundtrip() {
+        let params = DynResidueParams::new(&U256::from(0xffu8));
+        let value = DynResidue::new(&U256::from(0xabu8), params);
+
+        assert_eq!(value.to_be_bytes(), U256::from(0xabu8).to_be_bytes());
+        assert_eq!(value.to_le_bytes(), U256::from(0xabu8).to_le_bytes());
+        assert_eq!(
+            DynResidue::from_be_bytes_canonical(value.to_be_bytes(), params).unwrap(),
+            value
+        );
+        assert_eq!(
+            DynResidue::from_le_bytes_canonical(value.to_le_bytes(), params).unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn bytes_non_canonical() {
+        let params = DynResidueParams::new(&U256::from(0xffu8));
+
+        let max = U256::from(0xfeu8).to_be_bytes();
+        assert!(bool::from(
+            DynResidue::from_be_bytes_canonical(max, params).is_some()
+        ));
+        let modulus = U256::from(0xffu8).to_be_bytes();
+        assert!(bool::from(
+            DynResidue::from_be_bytes_canonical(modulus, params).is_none()
+        ));
+        let modulus = U256::from(0xffu8).to_le_bytes();
+        assert!(bool::from(
+            DynResidue::from_le_bytes_canonical(modulus, params).is_none()
+        ));
+    }
+
+    #[cfg(feature = "generic-array")]
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn byte_array_roundtrip() {
+        let params = DynResidueParams::new(&U256::from(0xffu8));
+        let value = DynResidue::new(&U256::from(0xabu8), params);
+
+        assert_eq!(
+            DynResidue::from_be_byte_array_canonical(value.to_be_byte_array(), params).unwrap(),
+            value
+        );
+        assert_eq!(
+            DynResidue::from_le_byte_array_canonical(value.to_le_byte_array(), params).unwrap(),
+            value
+        );
+    }
 }
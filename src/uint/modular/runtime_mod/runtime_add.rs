@@ -1,10 +1,26 @@
 use core::ops::{Add, AddAssign};
+use subtle::{ConstantTimeEq, CtOption};
 
 use crate::modular::add::add_montgomery_form;
 
-use super::DynResidue;
+use super::{DynResidue, ParamsMismatchError};
 
 impl<const LIMBS: usize> DynResidue<LIMBS> {
+    /// Adds `rhs`, returning `None` if `self` and `rhs` have different parameters.
+    ///
+    /// The parameters are compared in constant time.
+    pub fn checked_add(&self, rhs: &Self) -> CtOption<Self> {
+        CtOption::new(
+            self.add(rhs),
+            self.residue_params.ct_eq(&rhs.residue_params),
+        )
+    }
+
+    /// Adds `rhs`, returning an error if `self` and `rhs` have different parameters.
+    pub fn try_add(&self, rhs: &Self) -> Result<Self, ParamsMismatchError> {
+        Option::from(self.checked_add(rhs)).ok_or(ParamsMismatchError)
+    }
+
     /// Adds `rhs`.
     pub const fn add(&self, rhs: &Self) -> Self {
         Self {
@@ -21,7 +37,7 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
 impl<const LIMBS: usize> Add<&DynResidue<LIMBS>> for &DynResidue<LIMBS> {
     type Output = DynResidue<LIMBS>;
     fn add(self, rhs: &DynResidue<LIMBS>) -> DynResidue<LIMBS> {
-        debug_assert_eq!(self.residue_params, rhs.residue_params);
+        self.assert_same_params(rhs);
         self.add(rhs)
     }
 }
@@ -62,6 +78,7 @@ impl<const LIMBS: usize> AddAssign<DynResidue<LIMBS>> for DynResidue<LIMBS> {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
     use crate::{
         modular::runtime_mod::{DynResidue, DynResidueParams},
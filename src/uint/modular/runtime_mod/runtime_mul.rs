@@ -1,13 +1,29 @@
 use core::ops::{Mul, MulAssign};
+use subtle::{ConstantTimeEq, CtOption};
 
 use crate::{
-    modular::mul::{mul_montgomery_form, square_montgomery_form},
+    modular::mul::{mul_montgomery_form, mul_montgomery_form_assign, square_montgomery_form},
     traits::Square,
 };
 
-use super::DynResidue;
+use super::{DynResidue, ParamsMismatchError};
 
 impl<const LIMBS: usize> DynResidue<LIMBS> {
+    /// Multiplies by `rhs`, returning `None` if `self` and `rhs` have different parameters.
+    ///
+    /// The parameters are compared in constant time.
+    pub fn checked_mul(&self, rhs: &Self) -> CtOption<Self> {
+        CtOption::new(
+            self.mul(rhs),
+            self.residue_params.ct_eq(&rhs.residue_params),
+        )
+    }
+
+    /// Multiplies by `rhs`, returning an error if `self` and `rhs` have different parameters.
+    pub fn try_mul(&self, rhs: &Self) -> Result<Self, ParamsMismatchError> {
+        Option::from(self.checked_mul(rhs)).ok_or(ParamsMismatchError)
+    }
+
     /// Multiplies by `rhs`.
     pub fn mul(&self, rhs: &Self) -> Self {
         Self {
@@ -21,6 +37,17 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
         }
     }
 
+    /// Multiplies by `rhs` in place, without allocating temporaries for the product.
+    pub fn montgomery_mul_assign(&mut self, rhs: &Self) {
+        self.assert_same_params(rhs);
+        mul_montgomery_form_assign(
+            &mut self.montgomery_form,
+            &rhs.montgomery_form,
+            &self.residue_params.modulus,
+            self.residue_params.mod_neg_inv,
+        );
+    }
+
     /// Computes the (reduced) square of a residue.
     pub fn square(&self) -> Self {
         Self {
@@ -37,7 +64,7 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
 impl<const LIMBS: usize> Mul<&DynResidue<LIMBS>> for &DynResidue<LIMBS> {
     type Output = DynResidue<LIMBS>;
     fn mul(self, rhs: &DynResidue<LIMBS>) -> DynResidue<LIMBS> {
-        debug_assert_eq!(self.residue_params, rhs.residue_params);
+        self.assert_same_params(rhs);
         self.mul(rhs)
     }
 }
@@ -67,7 +94,7 @@ impl<const LIMBS: usize> Mul<DynResidue<LIMBS>> for DynResidue<LIMBS> {
 
 impl<const LIMBS: usize> MulAssign<&DynResidue<LIMBS>> for DynResidue<LIMBS> {
     fn mul_assign(&mut self, rhs: &DynResidue<LIMBS>) {
-        *self = *self * rhs;
+        self.montgomery_mul_assign(rhs);
     }
 }
 
@@ -82,3 +109,33 @@ impl<const LIMBS: usize> Square for DynResidue<LIMBS> {
         DynResidue::square(self)
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{
+        modular::runtime_mod::{DynResidue, DynResidueParams},
+        U256,
+    };
+
+    #[test]
+    fn montgomery_mul_assign() {
+        let params = DynResidueParams::new(&U256::from_be_hex(
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff43",
+        ));
+
+        let x =
+            U256::from_be_hex("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff42");
+        let y =
+            U256::from_be_hex("d5777c45019673125ad240f83094d4252d829516fac8601ed01979ec1ec1a251");
+        let x_mod = DynResidue::new(&x, params);
+        let y_mod = DynResidue::new(&y, params);
+
+        let mut actual = x_mod;
+        actual.montgomery_mul_assign(&y_mod);
+        assert_eq!(actual, x_mod * y_mod);
+
+        actual.montgomery_mul_assign(&x_mod);
+        assert_eq!(actual.retrieve(), y);
+    }
+}
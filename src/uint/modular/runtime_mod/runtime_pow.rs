@@ -1,10 +1,24 @@
-use super::DynResidue;
+use super::{DynResidue, DynResidueParams, ParamsMismatchError};
 use crate::modular::pow::multi_exponentiate_montgomery_form_array;
 #[cfg(feature = "alloc")]
-use crate::modular::pow::multi_exponentiate_montgomery_form_slice;
-use crate::{modular::pow::pow_montgomery_form, MultiExponentiateBoundedExp, PowBoundedExp, Uint};
+use crate::modular::pow::{
+    multi_exponentiate_montgomery_form_slice, multi_exponentiate_montgomery_form_vartime,
+};
+use crate::{
+    modular::pow::{
+        pow_montgomery_form, pow_montgomery_form_ladder, pow_montgomery_form_ladder_checked,
+        pow_montgomery_form_with_window, Window,
+    },
+    Limb, MultiExponentiateBoundedExp, PowBoundedExp, Uint,
+};
+#[cfg(feature = "rand_core")]
+use crate::{
+    modular::pow::{pow_montgomery_form_ladder_blinded, pow_montgomery_form_ladder_exp_blinded},
+    rand_core::CryptoRngCore,
+};
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+use subtle::{ConstantTimeEq, CtOption};
 
 impl<const LIMBS: usize> DynResidue<LIMBS> {
     /// Raises to the `exponent` power.
@@ -34,6 +48,176 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
             residue_params: self.residue_params,
         }
     }
+
+    /// Raises to the `exponent` power using a fixed window of `W::BITS` bits.
+    ///
+    /// [`Self::pow`] uses a 4-bit window, i.e. [`Window4`][`crate::modular::Window4`].
+    /// Wider windows are faster for large moduli, narrower ones use less stack for the
+    /// table of powers.
+    pub fn pow_with_window<W: Window>(&self, exponent: &impl AsRef<[Limb]>) -> Self {
+        self.pow_bounded_exp_with_window::<W>(exponent, exponent.as_ref().len() * Limb::BITS)
+    }
+
+    /// Raises to the `exponent` power using a fixed window of `W::BITS` bits,
+    /// with `exponent_bits` representing the number of (least significant) bits
+    /// to take into account for the exponent.
+    ///
+    /// NOTE: `exponent_bits` may be leaked in the time pattern.
+    pub fn pow_bounded_exp_with_window<W: Window>(
+        &self,
+        exponent: &impl AsRef<[Limb]>,
+        exponent_bits: usize,
+    ) -> Self {
+        Self {
+            montgomery_form: pow_montgomery_form_with_window::<LIMBS, W>(
+                &self.montgomery_form,
+                exponent.as_ref(),
+                exponent_bits,
+                &self.residue_params.modulus,
+                &self.residue_params.r,
+                self.residue_params.mod_neg_inv,
+            ),
+            residue_params: self.residue_params,
+        }
+    }
+
+    /// Raises to the `exponent` power using Montgomery's ladder.
+    ///
+    /// Unlike [`Self::pow`], every exponent bit is processed with the same sequence
+    /// of one multiplication and one squaring.
+    pub fn pow_ladder<const RHS_LIMBS: usize>(&self, exponent: &Uint<RHS_LIMBS>) -> Self {
+        self.pow_ladder_bounded_exp(exponent, Uint::<RHS_LIMBS>::BITS)
+    }
+
+    /// Raises to the `exponent` power using Montgomery's ladder,
+    /// with `exponent_bits` representing the number of (least significant) bits
+    /// to take into account for the exponent.
+    ///
+    /// NOTE: `exponent_bits` may be leaked in the time pattern.
+    pub fn pow_ladder_bounded_exp<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Uint<RHS_LIMBS>,
+        exponent_bits: usize,
+    ) -> Self {
+        let (montgomery_form, _) = pow_montgomery_form_ladder(
+            &self.montgomery_form,
+            exponent,
+            exponent_bits,
+            &self.residue_params.modulus,
+            &self.residue_params.r,
+            self.residue_params.mod_neg_inv,
+        );
+        self.with_montgomery_form(montgomery_form)
+    }
+
+    /// Raises to the `exponent` power using Montgomery's ladder, and checks afterwards
+    /// that the invariant of the ladder still holds.
+    ///
+    /// Returns `None` if one of the ladder registers was corrupted during the computation.
+    /// Faults on the exponent bits or on the conditional swaps are not detected.
+    pub fn pow_ladder_checked<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Uint<RHS_LIMBS>,
+    ) -> CtOption<Self> {
+        let (montgomery_form, is_valid) = pow_montgomery_form_ladder_checked(
+            &self.montgomery_form,
+            exponent,
+            Uint::<RHS_LIMBS>::BITS,
+            &self.residue_params.modulus,
+            &self.residue_params.r,
+            self.residue_params.mod_neg_inv,
+        );
+        CtOption::new(self.with_montgomery_form(montgomery_form), is_valid.into())
+    }
+
+    /// Raises to the `exponent` power using Montgomery's ladder with exponent blinding,
+    /// and checks afterwards that the invariant of the ladder still holds.
+    ///
+    /// The exponent is replaced with `exponent + k * order` for a random limb `k`.
+    /// `order` must be a multiple of the multiplicative order of `self`
+    /// (e.g. `p - 1` for a prime modulus `p`, or Euler's totient of the modulus).
+    ///
+    /// Returns `None` under the same conditions as [`Self::pow_ladder_checked`].
+    #[cfg(feature = "rand_core")]
+    pub fn pow_ladder_exp_blinded<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Uint<RHS_LIMBS>,
+        order: &Uint<RHS_LIMBS>,
+        rng: &mut impl CryptoRngCore,
+    ) -> CtOption<Self> {
+        let (montgomery_form, is_valid) = pow_montgomery_form_ladder_exp_blinded(
+            &self.montgomery_form,
+            exponent,
+            order,
+            rng,
+            &self.residue_params.modulus,
+            &self.residue_params.r,
+            self.residue_params.mod_neg_inv,
+        );
+        CtOption::new(self.with_montgomery_form(montgomery_form), is_valid.into())
+    }
+
+    /// Raises to the `exponent` power using Montgomery's ladder with base and exponent blinding,
+    /// and checks afterwards that the invariant of the ladders still holds.
+    ///
+    /// The base is multiplied by a random mask `m`, and the result by `(m^-1)^exponent`,
+    /// which is computed with a second ladder, so this costs twice as much as
+    /// [`Self::pow_ladder_exp_blinded`]. Both ladders use independently blinded exponents.
+    ///
+    /// Since the mask is blinded as well, `order` must be a multiple of the order of every
+    /// invertible residue, i.e. the exponent of the multiplicative group (e.g. `p - 1` for a
+    /// prime modulus `p`, or the Carmichael function of the modulus). Passing [`Uint::ZERO`]
+    /// disables exponent blinding.
+    ///
+    /// Returns `None` under the same conditions as [`Self::pow_ladder_checked`].
+    #[cfg(feature = "rand_core")]
+    pub fn pow_ladder_blinded<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Uint<RHS_LIMBS>,
+        order: &Uint<RHS_LIMBS>,
+        rng: &mut impl CryptoRngCore,
+    ) -> CtOption<Self> {
+        let (montgomery_form, is_valid) = pow_montgomery_form_ladder_blinded(
+            &self.montgomery_form,
+            exponent,
+            order,
+            rng,
+            &self.residue_params.modulus,
+            &self.residue_params.r,
+            &self.residue_params.r3,
+            self.residue_params.mod_neg_inv,
+        );
+        CtOption::new(self.with_montgomery_form(montgomery_form), is_valid.into())
+    }
+
+    fn with_montgomery_form(&self, montgomery_form: Uint<LIMBS>) -> Self {
+        Self {
+            montgomery_form,
+            residue_params: self.residue_params,
+        }
+    }
+
+    /// Raises to the `exponent` power, returning `None` if `self` was not created
+    /// with the given `params`.
+    ///
+    /// The parameters are compared in constant time.
+    pub fn checked_pow<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Uint<RHS_LIMBS>,
+        params: &DynResidueParams<LIMBS>,
+    ) -> CtOption<Self> {
+        CtOption::new(self.pow(exponent), self.residue_params.ct_eq(params))
+    }
+
+    /// Raises to the `exponent` power, returning an error if `self` was not created
+    /// with the given `params`.
+    pub fn try_pow<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Uint<RHS_LIMBS>,
+        params: &DynResidueParams<LIMBS>,
+    ) -> Result<Self, ParamsMismatchError> {
+        Option::from(self.checked_pow(exponent, params)).ok_or(ParamsMismatchError)
+    }
 }
 
 impl<const LIMBS: usize, const RHS_LIMBS: usize> PowBoundedExp<Uint<RHS_LIMBS>>
@@ -61,6 +245,7 @@ impl<const N: usize, const LIMBS: usize, const RHS_LIMBS: usize>
         let mut i = 0;
         while i < N {
             let (base, exponent) = bases_and_exponents[i];
+            bases_and_exponents[0].0.assert_same_params(&base);
             bases_and_exponents_montgomery_form[i] = (base.montgomery_form, exponent);
             i += 1;
         }
@@ -76,6 +261,17 @@ impl<const N: usize, const LIMBS: usize, const RHS_LIMBS: usize>
             residue_params,
         }
     }
+
+    #[cfg(feature = "alloc")]
+    fn multi_exponentiate_bounded_exp_vartime(
+        bases_and_exponents: &[(Self, Uint<RHS_LIMBS>); N],
+        exponent_bits: usize,
+    ) -> Self {
+        <Self as MultiExponentiateBoundedExp<_, [(Self, Uint<RHS_LIMBS>)]>>::multi_exponentiate_bounded_exp_vartime(
+            bases_and_exponents.as_slice(),
+            exponent_bits,
+        )
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -90,11 +286,15 @@ impl<const LIMBS: usize, const RHS_LIMBS: usize>
             !bases_and_exponents.is_empty(),
             "bases_and_exponents must not be empty"
         );
-        let residue_params = bases_and_exponents[0].0.residue_params;
+        let first = bases_and_exponents[0].0;
+        let residue_params = first.residue_params;
 
         let bases_and_exponents: Vec<(Uint<LIMBS>, Uint<RHS_LIMBS>)> = bases_and_exponents
             .iter()
-            .map(|(base, exp)| (base.montgomery_form, *exp))
+            .map(|(base, exp)| {
+                first.assert_same_params(base);
+                (base.montgomery_form, *exp)
+            })
             .collect();
         Self {
             montgomery_form: multi_exponentiate_montgomery_form_slice(
@@ -107,12 +307,46 @@ impl<const LIMBS: usize, const RHS_LIMBS: usize>
             residue_params,
         }
     }
+
+    fn multi_exponentiate_bounded_exp_vartime(
+        bases_and_exponents: &[(Self, Uint<RHS_LIMBS>)],
+        exponent_bits: usize,
+    ) -> Self {
+        assert!(
+            !bases_and_exponents.is_empty(),
+            "bases_and_exponents must not be empty"
+        );
+        let first = bases_and_exponents[0].0;
+        let residue_params = first.residue_params;
+
+        let bases_and_exponents: Vec<(Uint<LIMBS>, Uint<RHS_LIMBS>)> = bases_and_exponents
+            .iter()
+            .map(|(base, exp)| {
+                first.assert_same_params(base);
+                (base.montgomery_form, *exp)
+            })
+            .collect();
+        Self {
+            montgomery_form: multi_exponentiate_montgomery_form_vartime(
+                &bases_and_exponents,
+                exponent_bits,
+                &residue_params.modulus,
+                &residue_params.r,
+                residue_params.mod_neg_inv,
+            ),
+            residue_params,
+        }
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
     use crate::{
-        modular::runtime_mod::{DynResidue, DynResidueParams},
+        modular::{
+            runtime_mod::{DynResidue, DynResidueParams},
+            Window1, Window3, Window6,
+        },
         U256,
     };
 
@@ -188,4 +422,149 @@ mod tests {
             U256::from_be_hex("3681BC0FEA2E5D394EB178155A127B0FD2EF405486D354251C385BDD51B9D421");
         assert_eq!(res.retrieve(), expected);
     }
+
+    #[test]
+    fn test_powmod_with_window() {
+        let params = DynResidueParams::new(&U256::from_be_hex(
+            "9CC24C5DF431A864188AB905AC751B727C9447A8E99E6366E1AD78A21E8D882B",
+        ));
+
+        let base =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let base_mod = DynResidue::new(&base, params);
+
+        let exponent = U256::from(105u64);
+
+        let expected =
+            U256::from_be_hex("89E2A4E99F649A5AE2C18068148C355CA927B34A3245C938178ED00D6EF218AA");
+        assert_eq!(
+            base_mod.pow_with_window::<Window1>(&exponent).retrieve(),
+            expected
+        );
+        assert_eq!(
+            base_mod.pow_with_window::<Window3>(&exponent).retrieve(),
+            expected
+        );
+        assert_eq!(
+            base_mod.pow_with_window::<Window6>(&exponent).retrieve(),
+            expected
+        );
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_pow_ladder() {
+        let params = DynResidueParams::new(&U256::from_be_hex(
+            "9CC24C5DF431A864188AB905AC751B727C9447A8E99E6366E1AD78A21E8D882B",
+        ));
+
+        let base =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let base_mod = DynResidue::new(&base, params);
+
+        let exponent =
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+
+        let expected =
+            U256::from_be_hex("3681BC0FEA2E5D394EB178155A127B0FD2EF405486D354251C385BDD51B9D421");
+        assert_eq!(base_mod.pow_ladder(&exponent).retrieve(), expected);
+        assert_eq!(
+            base_mod.pow_ladder_checked(&exponent).unwrap().retrieve(),
+            expected
+        );
+        assert_eq!(base_mod.pow_ladder(&U256::ZERO).retrieve(), U256::ONE);
+
+        for exponent_bits in [0, 1, 64, 100, 255] {
+            assert_eq!(
+                base_mod.pow_ladder_bounded_exp(&exponent, exponent_bits),
+                base_mod.pow_bounded_exp(&exponent, exponent_bits)
+            );
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_pow_ladder_blinded() {
+        use rand_core::OsRng;
+
+        // NIST P-256 base field prime, so the multiplicative order divides `p - 1`
+        let p =
+            U256::from_be_hex("FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF");
+        let order = p.wrapping_sub(&U256::ONE);
+        let params = DynResidueParams::new(&p);
+
+        let base =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let base_mod = DynResidue::new(&base, params);
+
+        let exponent =
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+        let expected = base_mod.pow(&exponent);
+
+        for _ in 0..4 {
+            assert_eq!(
+                base_mod
+                    .pow_ladder_exp_blinded(&exponent, &order, &mut OsRng)
+                    .unwrap(),
+                expected
+            );
+            assert_eq!(
+                base_mod
+                    .pow_ladder_blinded(&exponent, &order, &mut OsRng)
+                    .unwrap(),
+                expected
+            );
+            assert_eq!(
+                base_mod
+                    .pow_ladder_blinded(&exponent, &U256::ZERO, &mut OsRng)
+                    .unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_multi_exp_vartime() {
+        use crate::traits::{MultiExponentiate, MultiExponentiateBoundedExp};
+        use alloc::vec::Vec;
+
+        let params = DynResidueParams::new(&U256::from_be_hex(
+            "9CC24C5DF431A864188AB905AC751B727C9447A8E99E6366E1AD78A21E8D882B",
+        ));
+        let exponent =
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+
+        for n in [1u64, 3, 40] {
+            let bases_and_exponents: Vec<(DynResidue<{ U256::LIMBS }>, U256)> = (1..=n)
+                .map(|i| {
+                    let base = DynResidue::new(&U256::from(i * 7919 + 3), params);
+                    (base, exponent.wrapping_mul(&U256::from(i)))
+                })
+                .collect();
+
+            let expected = bases_and_exponents
+                .iter()
+                .fold(DynResidue::one(params), |acc, (base, exp)| {
+                    acc * base.pow(exp)
+                });
+
+            assert_eq!(
+                DynResidue::multi_exponentiate(bases_and_exponents.as_slice()),
+                expected
+            );
+            assert_eq!(
+                DynResidue::multi_exponentiate_vartime(bases_and_exponents.as_slice()),
+                expected
+            );
+            assert_eq!(
+                DynResidue::multi_exponentiate_bounded_exp_vartime(
+                    bases_and_exponents.as_slice(),
+                    0
+                ),
+                DynResidue::one(params)
+            );
+        }
+    }
 }
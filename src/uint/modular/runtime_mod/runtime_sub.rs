@@ -1,10 +1,26 @@
 use core::ops::{Sub, SubAssign};
+use subtle::{ConstantTimeEq, CtOption};
 
 use crate::modular::sub::sub_montgomery_form;
 
-use super::DynResidue;
+use super::{DynResidue, ParamsMismatchError};
 
 impl<const LIMBS: usize> DynResidue<LIMBS> {
+    /// Subtracts `rhs`, returning `None` if `self` and `rhs` have different parameters.
+    ///
+    /// The parameters are compared in constant time.
+    pub fn checked_sub(&self, rhs: &Self) -> CtOption<Self> {
+        CtOption::new(
+            self.sub(rhs),
+            self.residue_params.ct_eq(&rhs.residue_params),
+        )
+    }
+
+    /// Subtracts `rhs`, returning an error if `self` and `rhs` have different parameters.
+    pub fn try_sub(&self, rhs: &Self) -> Result<Self, ParamsMismatchError> {
+        Option::from(self.checked_sub(rhs)).ok_or(ParamsMismatchError)
+    }
+
     /// Subtracts `rhs`.
     pub const fn sub(&self, rhs: &Self) -> Self {
         Self {
@@ -21,7 +37,7 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
 impl<const LIMBS: usize> Sub<&DynResidue<LIMBS>> for &DynResidue<LIMBS> {
     type Output = DynResidue<LIMBS>;
     fn sub(self, rhs: &DynResidue<LIMBS>) -> DynResidue<LIMBS> {
-        debug_assert_eq!(self.residue_params, rhs.residue_params);
+        self.assert_same_params(rhs);
         self.sub(rhs)
     }
 }
@@ -62,6 +78,7 @@ impl<const LIMBS: usize> SubAssign<DynResidue<LIMBS>> for DynResidue<LIMBS> {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
     use crate::{
         modular::runtime_mod::{DynResidue, DynResidueParams},
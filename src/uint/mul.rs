@@ -1,6 +1,9 @@
 //! [`Uint`] addition operations.
 
-use crate::{Checked, CheckedMul, Concat, ConcatMixed, Limb, Uint, WideWord, Word, Wrapping, Zero};
+use crate::{
+    Checked, CheckedMul, Concat, ConcatMixed, CtChoice, Limb, Square, Uint, WideWord, Word,
+    Wrapping, Zero,
+};
 use core::ops::{Mul, MulAssign};
 use subtle::CtOption;
 
@@ -66,6 +69,104 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         (lo, hi)
     }
 
+    /// Compute "wide" multiplication in place, writing the product into `lo` and `hi`.
+    ///
+    /// This is the same as [`Self::mul_wide`], but avoids the temporaries for the result.
+    pub fn mul_wide_into<const HLIMBS: usize>(
+        &self,
+        rhs: &Uint<HLIMBS>,
+        lo: &mut Self,
+        hi: &mut Uint<HLIMBS>,
+    ) {
+        lo.limbs = [Limb::ZERO; LIMBS];
+        hi.limbs = [Limb::ZERO; HLIMBS];
+
+        let mut i = 0;
+        while i < LIMBS {
+            let mut j = 0;
+            let mut carry = Limb::ZERO;
+
+            while j < HLIMBS {
+                let k = i + j;
+
+                if k >= LIMBS {
+                    (hi.limbs[k - LIMBS], carry) =
+                        hi.limbs[k - LIMBS].mac(self.limbs[i], rhs.limbs[j], carry);
+                } else {
+                    (lo.limbs[k], carry) = lo.limbs[k].mac(self.limbs[i], rhs.limbs[j], carry);
+                }
+
+                j += 1;
+            }
+
+            if i + j >= LIMBS {
+                hi.limbs[i + j - LIMBS] = carry;
+            } else {
+                lo.limbs[i + j] = carry;
+            }
+            i += 1;
+        }
+    }
+
+    /// Multiply `self` by `rhs`, writing the product into an integer of arbitrary width `OUT`.
+    ///
+    /// If the product doesn't fit into `OUT` limbs, it is truncated and the returned
+    /// [`CtChoice`] is truthy.
+    pub const fn mul_into<const OUT: usize, const HLIMBS: usize>(
+        &self,
+        rhs: &Uint<HLIMBS>,
+    ) -> (Uint<OUT>, CtChoice) {
+        let mut res = Uint::<OUT>::ZERO;
+
+        // The limbs of the product above `OUT`, split between two buffers which together
+        // can hold the whole product.
+        let mut lo_spill = [Limb::ZERO; LIMBS];
+        let mut hi_spill = [Limb::ZERO; HLIMBS];
+
+        let mut i = 0;
+        while i < LIMBS {
+            let mut j = 0;
+            let mut carry = Limb::ZERO;
+
+            while j <= HLIMBS {
+                let k = i + j;
+                let y = if j < HLIMBS { rhs.limbs[j] } else { Limb::ZERO };
+
+                if k < OUT {
+                    let (n, c) = res.limbs[k].mac(self.limbs[i], y, carry);
+                    res.limbs[k] = n;
+                    carry = c;
+                } else if k - OUT < LIMBS {
+                    let (n, c) = lo_spill[k - OUT].mac(self.limbs[i], y, carry);
+                    lo_spill[k - OUT] = n;
+                    carry = c;
+                } else {
+                    let (n, c) = hi_spill[k - OUT - LIMBS].mac(self.limbs[i], y, carry);
+                    hi_spill[k - OUT - LIMBS] = n;
+                    carry = c;
+                }
+
+                j += 1;
+            }
+
+            i += 1;
+        }
+
+        let mut acc = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            acc |= lo_spill[i].0;
+            i += 1;
+        }
+        let mut i = 0;
+        while i < HLIMBS {
+            acc |= hi_spill[i].0;
+            i += 1;
+        }
+
+        (res, Limb(acc).ct_is_nonzero())
+    }
+
     /// Perform saturating multiplication, returning `MAX` on overflow.
     pub const fn saturating_mul<const HLIMBS: usize>(&self, rhs: &Uint<HLIMBS>) -> Self {
         let (res, overflow) = self.mul_wide(rhs);
@@ -77,6 +178,28 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         self.mul_wide(rhs).0
     }
 
+    /// Computes `self * rhs`, returning the low half of the product along with
+    /// [`CtChoice::TRUE`] if the high half is non-zero.
+    pub const fn overflowing_mul<const HLIMBS: usize>(
+        &self,
+        rhs: &Uint<HLIMBS>,
+    ) -> (Self, CtChoice) {
+        let (lo, hi) = self.mul_wide(rhs);
+        (lo, hi.ct_is_nonzero())
+    }
+
+    /// Computes `self * rhs + carry + addend`, returning the "wide" result as `(lo, hi)`.
+    ///
+    /// The result always fits in twice the precision of `self`, so this never overflows.
+    pub const fn carrying_mul_add(&self, rhs: &Self, carry: &Self, addend: &Self) -> (Self, Self) {
+        let (lo, hi) = self.mul_wide(rhs);
+        let (lo, c1) = lo.adc(carry, Limb::ZERO);
+        let (lo, c2) = lo.adc(addend, Limb::ZERO);
+        let (hi, _) = hi.adc(&Self::ZERO, c1);
+        let (hi, _) = hi.adc(&Self::ZERO, c2);
+        (lo, hi)
+    }
+
     /// Square self, returning a concatenated "wide" result.
     pub fn square(&self) -> <Self as Concat>::Output
     where
@@ -127,8 +250,12 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         }
 
         // Double the current result, this accounts for the other half of the multiplication grid.
-        // TODO: The top word is empty so we can also use a special purpose shl.
-        (lo, hi) = Self::shl_vartime_wide((lo, hi), 1);
+        // The top bit is always empty, so the bit shifted out of `hi` can be discarded.
+        let (new_lo, carry) = lo.shl_limb(1);
+        let (new_hi, _) = hi.shl_limb(1);
+        lo = new_lo;
+        hi = new_hi;
+        hi.limbs[0] = hi.limbs[0].bitor(carry);
 
         // Handle the diagonal of the multiplication grid, which finishes the multiplication grid.
         let mut carry = Limb::ZERO;
@@ -170,6 +297,14 @@ impl<const LIMBS: usize, const HLIMBS: usize> CheckedMul<&Uint<HLIMBS>> for Uint
     }
 }
 
+impl<const LIMBS: usize> CheckedMul<&Wrapping<Uint<LIMBS>>> for Wrapping<Uint<LIMBS>> {
+    type Output = Self;
+
+    fn checked_mul(&self, rhs: &Self) -> CtOption<Self> {
+        self.0.checked_mul(&rhs.0).map(Wrapping)
+    }
+}
+
 impl<const LIMBS: usize, const HLIMBS: usize> Mul<Wrapping<Uint<HLIMBS>>>
     for Wrapping<Uint<LIMBS>>
 {
@@ -320,9 +455,24 @@ where
     }
 }
 
+impl<const LIMBS: usize> Square for Wrapping<Uint<LIMBS>> {
+    fn square(&self) -> Self {
+        Wrapping(self.0.square_wide().0)
+    }
+}
+
+impl<const LIMBS: usize> Square for Checked<Uint<LIMBS>> {
+    fn square(&self) -> Self {
+        Checked(self.0.and_then(|x| {
+            let (lo, hi) = x.square_wide();
+            CtOption::new(lo, hi.is_zero())
+        }))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{CheckedMul, Zero, U128, U192, U256, U64};
+    use crate::{Checked, CheckedMul, Square, Wrapping, Zero, U128, U192, U256, U64};
 
     #[test]
     fn mul_wide_zero_and_one() {
@@ -368,6 +518,49 @@ mod tests {
         assert_eq!(b * a, U192::from(&b).saturating_mul(&a));
     }
 
+    #[test]
+    fn mul_wide_into() {
+        let a = U128::from_u128(0x8899aabbccddeeff_8899aabbccddeeff);
+        let b = U192::MAX;
+        let (mut lo, mut hi) = (U128::MAX, U192::MAX);
+        a.mul_wide_into(&b, &mut lo, &mut hi);
+        assert_eq!((lo, hi), a.mul_wide(&b));
+
+        let mut lo = U64::ONE;
+        U64::MAX.mul_wide_into(&U192::ZERO, &mut lo, &mut hi);
+        assert_eq!((lo, hi), (U64::ZERO, U192::ZERO));
+    }
+
+    #[test]
+    fn mul_into_wider() {
+        let a = U128::from_u128(0x8899aabbccddeeff_8899aabbccddeeff);
+        let b = U64::from_u64(0x0011223344556677);
+        let (res, overflow): (U256, _) = a.mul_into(&b);
+        assert_eq!(res, U192::from(&a).saturating_mul(&b).resize());
+        assert!(!overflow.is_true_vartime());
+
+        let (res, overflow): (U256, _) = U128::MAX.mul_into(&U128::MAX);
+        assert_eq!(res, U128::MAX * U128::MAX);
+        assert!(!overflow.is_true_vartime());
+    }
+
+    #[test]
+    fn mul_into_truncated() {
+        let a = U128::from_u128(0x8899aabbccddeeff_8899aabbccddeeff);
+        let b = U64::from_u64(0x0011223344556677);
+        let (res, overflow): (U128, _) = a.mul_into(&b);
+        assert_eq!(res, a.wrapping_mul(&b));
+        assert!(overflow.is_true_vartime());
+
+        let (res, overflow): (U64, _) = U64::MAX.mul_into(&U64::ONE);
+        assert_eq!(res, U64::MAX);
+        assert!(!overflow.is_true_vartime());
+
+        let (res, overflow): (U64, _) = U192::MAX.mul_into(&U192::MAX);
+        assert_eq!(res, U64::ONE);
+        assert!(overflow.is_true_vartime());
+    }
+
     #[test]
     fn checked_mul_ok() {
         let n = U64::from_u32(0xffff_ffff);
@@ -411,4 +604,35 @@ mod tests {
         assert_eq!(lo, U256::ONE);
         assert_eq!(hi, U256::MAX.wrapping_sub(&U256::ONE));
     }
+
+    #[test]
+    fn square_wrapping_checked() {
+        let x = U128::from_u64(u64::MAX);
+        assert_eq!(Wrapping(x).square(), Wrapping(x.wrapping_mul(&x)));
+        assert_eq!(Checked::new(x).square().0.unwrap(), x.wrapping_mul(&x));
+
+        let y = U128::MAX;
+        assert_eq!(Wrapping(y).square(), Wrapping(U128::ONE));
+        assert!(bool::from(Checked::new(y).square().0.is_none()));
+    }
+
+    #[test]
+    fn overflowing_mul() {
+        let (res, overflow) = U128::from(3u8).overflowing_mul(&U64::from(5u8));
+        assert_eq!(res, U128::from(15u8));
+        assert!(!bool::from(overflow));
+
+        let (res, overflow) = U128::MAX.overflowing_mul(&U128::from(2u8));
+        assert_eq!(res, U128::MAX.wrapping_sub(&U128::ONE));
+        assert!(bool::from(overflow));
+    }
+
+    #[test]
+    fn carrying_mul_add() {
+        let (lo, hi) = U128::MAX.carrying_mul_add(&U128::MAX, &U128::MAX, &U128::MAX);
+        assert_eq!((lo, hi), (U128::MAX, U128::MAX));
+
+        let (lo, hi) = U128::MAX.carrying_mul_add(&U128::from(2u8), &U128::ONE, &U128::ONE);
+        assert_eq!((lo, hi), (U128::ZERO, U128::from(2u8)));
+    }
 }
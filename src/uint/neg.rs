@@ -29,6 +29,12 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         }
         Uint::new(ret)
     }
+
+    /// Computes `-self`, returning the result along with [`CtChoice::TRUE`]
+    /// if `self` is non-zero (i.e. the negation wrapped around).
+    pub const fn overflowing_neg(&self) -> (Self, CtChoice) {
+        (self.wrapping_neg(), self.ct_is_nonzero())
+    }
 }
 
 #[cfg(test)]
@@ -48,4 +54,15 @@ mod tests {
             U256::from_u64(42).saturating_sub(&U256::ONE).not()
         );
     }
+
+    #[test]
+    fn overflowing_neg() {
+        let (res, overflow) = U256::ZERO.overflowing_neg();
+        assert_eq!(res, U256::ZERO);
+        assert!(!bool::from(overflow));
+
+        let (res, overflow) = U256::ONE.overflowing_neg();
+        assert_eq!(res, U256::MAX);
+        assert!(bool::from(overflow));
+    }
 }
@@ -0,0 +1,156 @@
+//! [`Uint`] exponentiation.
+
+use super::Uint;
+use crate::{Checked, CtChoice, PowBoundedExp, Wrapping};
+use subtle::CtOption;
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Computes `self^exponent`, discarding overflow.
+    pub const fn wrapping_pow<const RHS_LIMBS: usize>(&self, exponent: &Uint<RHS_LIMBS>) -> Self {
+        self.wrapping_pow_bounded_exp(exponent, Uint::<RHS_LIMBS>::BITS)
+    }
+
+    /// Computes `self^exponent`, discarding overflow,
+    /// with `exponent_bits` representing the number of (least significant) bits
+    /// to take into account for the exponent.
+    ///
+    /// NOTE: `exponent_bits` may be leaked in the time pattern.
+    pub const fn wrapping_pow_bounded_exp<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Uint<RHS_LIMBS>,
+        exponent_bits: usize,
+    ) -> Self {
+        self.overflowing_pow_bounded_exp(exponent, exponent_bits).0
+    }
+
+    /// Computes `self^exponent`, returning a [`CtOption`] which `is_some`
+    /// only if the result did not overflow.
+    pub fn checked_pow<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Uint<RHS_LIMBS>,
+    ) -> CtOption<Self> {
+        self.checked_pow_bounded_exp(exponent, Uint::<RHS_LIMBS>::BITS)
+    }
+
+    /// Computes `self^exponent`, returning a [`CtOption`] which `is_some`
+    /// only if the result did not overflow,
+    /// with `exponent_bits` representing the number of (least significant) bits
+    /// to take into account for the exponent.
+    ///
+    /// NOTE: `exponent_bits` may be leaked in the time pattern.
+    pub fn checked_pow_bounded_exp<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Uint<RHS_LIMBS>,
+        exponent_bits: usize,
+    ) -> CtOption<Self> {
+        let (result, overflow) = self.overflowing_pow_bounded_exp(exponent, exponent_bits);
+        CtOption::new(result, overflow.not().into())
+    }
+
+    /// Left-to-right square-and-multiply, returning the truthy value as the second element
+    /// of the tuple if the result overflowed.
+    ///
+    /// Every intermediate value divides the final result, so an overflow at any step means
+    /// the final result overflows as well (unless `self` is zero, which never overflows).
+    pub(crate) const fn overflowing_pow_bounded_exp<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Uint<RHS_LIMBS>,
+        exponent_bits: usize,
+    ) -> (Self, CtChoice) {
+        assert!(exponent_bits <= Uint::<RHS_LIMBS>::BITS);
+
+        let mut result = Self::ONE;
+        let mut overflow = CtChoice::FALSE;
+        let mut i = exponent_bits;
+        while i > 0 {
+            i -= 1;
+
+            let (lo, hi) = result.square_wide();
+            overflow = overflow.or(hi.ct_is_nonzero());
+            result = lo;
+
+            let bit = exponent.bit(i);
+            let (lo, hi) = result.mul_wide(self);
+            overflow = overflow.or(hi.ct_is_nonzero().and(bit));
+            result = Uint::ct_select(&result, &lo, bit);
+        }
+
+        (result, overflow)
+    }
+}
+
+impl<const LIMBS: usize, const RHS_LIMBS: usize> PowBoundedExp<Uint<RHS_LIMBS>>
+    for Wrapping<Uint<LIMBS>>
+{
+    fn pow_bounded_exp(&self, exponent: &Uint<RHS_LIMBS>, exponent_bits: usize) -> Self {
+        Wrapping(self.0.wrapping_pow_bounded_exp(exponent, exponent_bits))
+    }
+}
+
+impl<const LIMBS: usize, const RHS_LIMBS: usize> PowBoundedExp<Uint<RHS_LIMBS>>
+    for Checked<Uint<LIMBS>>
+{
+    fn pow_bounded_exp(&self, exponent: &Uint<RHS_LIMBS>, exponent_bits: usize) -> Self {
+        Checked(
+            self.0
+                .and_then(|base| base.checked_pow_bounded_exp(exponent, exponent_bits)),
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{Checked, Pow, Wrapping, U128, U64};
+
+    #[test]
+    fn wrapping_pow() {
+        let base = U128::from_u64(3);
+        assert_eq!(base.wrapping_pow(&U64::ZERO), U128::ONE);
+        assert_eq!(
+            base.wrapping_pow(&U64::from_u64(40)),
+            U128::from_u128(3u128.pow(40))
+        );
+        assert_eq!(
+            base.wrapping_pow(&U64::from_u64(100)),
+            U128::from_u128(3u128.wrapping_pow(100))
+        );
+        assert_eq!(U128::ZERO.wrapping_pow(&U64::from_u64(1000)), U128::ZERO);
+        assert_eq!(U128::ZERO.wrapping_pow(&U64::ZERO), U128::ONE);
+    }
+
+    #[test]
+    fn checked_pow() {
+        let base = U128::from_u64(3);
+        assert_eq!(
+            base.checked_pow(&U64::from_u64(80)).unwrap(),
+            U128::from_u128(3u128.pow(80))
+        );
+        assert!(bool::from(base.checked_pow(&U64::from_u64(81)).is_none()));
+        assert!(bool::from(
+            U128::ZERO.checked_pow(&U64::from_u64(1000)).is_some()
+        ));
+        assert!(bool::from(U128::MAX.checked_pow(&U64::ONE).is_some()));
+        assert!(bool::from(
+            U128::MAX.checked_pow(&U64::from_u64(2)).is_none()
+        ));
+    }
+
+    #[test]
+    fn pow_trait() {
+        let exp = U64::from_u64(100);
+        assert_eq!(
+            Wrapping(U128::from_u64(3)).pow(&exp),
+            Wrapping(U128::from_u128(3u128.wrapping_pow(100)))
+        );
+        assert!(bool::from(
+            Checked::new(U128::from_u64(3)).pow(&exp).0.is_none()
+        ));
+        assert!(bool::from(
+            Checked::new(U128::from_u64(2))
+                .pow(&U64::from_u64(127))
+                .0
+                .is_some()
+        ));
+    }
+}
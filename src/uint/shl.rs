@@ -1,7 +1,8 @@
 //! [`Uint`] bitwise left shift operations.
 
-use crate::{CtChoice, Limb, Uint, Word};
+use crate::{limb_slice, Checked, CtChoice, Limb, Uint, Word, Wrapping};
 use core::ops::{Shl, ShlAssign};
+use subtle::CtOption;
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes `self << shift` where `0 <= shift < Limb::BITS`,
@@ -59,6 +60,24 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         new_lower
     }
 
+    /// Computes `self <<= n` in place.
+    ///
+    /// NOTE: this operation is variable time with respect to `n` *ONLY*.
+    ///
+    /// When used with a fixed `n`, this function is constant-time with respect
+    /// to `self`.
+    pub fn shl_assign_vartime(&mut self, n: usize) {
+        if n >= Self::BITS {
+            self.limbs = [Limb::ZERO; LIMBS];
+            return;
+        }
+
+        let shift_num = n / Limb::BITS;
+        self.limbs.copy_within(..LIMBS - shift_num, shift_num);
+        self.limbs[..shift_num].fill(Limb::ZERO);
+        limb_slice::shl_n(&mut self.limbs[shift_num..], n % Limb::BITS);
+    }
+
     /// Computes a left shift on a wide input as `(lo, hi)`.
     ///
     /// NOTE: this operation is variable time with respect to `n` *ONLY*.
@@ -94,6 +113,49 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
         Uint::ct_select(&result, &Self::ZERO, overflow)
     }
+
+    /// Computes a left shift on a wide input as `(lo, hi)`.
+    /// Returns zero if `shift >= 2 * Self::BITS`.
+    ///
+    /// This is constant-time with respect to both the input and `shift`.
+    pub const fn shl_wide(lower_upper: (Self, Self), shift: usize) -> (Self, Self) {
+        let overflow = CtChoice::from_usize_lt(shift, 2 * Self::BITS).not();
+        let shift = shift % (2 * Self::BITS);
+        let (mut lower, mut upper) = lower_upper;
+        let mut i = 0;
+        while i <= Self::LOG2_BITS {
+            let bit = CtChoice::from_lsb((shift as Word >> i) & 1);
+            let (new_lower, new_upper) = Self::shl_vartime_wide((lower, upper), 1 << i);
+            lower = Uint::ct_select(&lower, &new_lower, bit);
+            upper = Uint::ct_select(&upper, &new_upper, bit);
+            i += 1;
+        }
+
+        (
+            Uint::ct_select(&lower, &Self::ZERO, overflow),
+            Uint::ct_select(&upper, &Self::ZERO, overflow),
+        )
+    }
+
+    /// Computes `self` rotated left by `shift % Self::BITS` bits.
+    ///
+    /// This is constant-time with respect to both `self` and `shift`.
+    pub const fn rotl(&self, shift: usize) -> Self {
+        let shift = shift % Self::BITS;
+        self.shl(shift).bitor(&self.shr(Self::BITS - shift))
+    }
+
+    /// Computes `self` rotated left by `shift % Self::BITS` bits.
+    ///
+    /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
+    ///
+    /// When used with a fixed `shift`, this function is constant-time with respect
+    /// to `self`.
+    pub const fn rotl_vartime(&self, shift: usize) -> Self {
+        let shift = shift % Self::BITS;
+        self.shl_vartime(shift)
+            .bitor(&self.shr_vartime(Self::BITS - shift))
+    }
 }
 
 impl<const LIMBS: usize> Shl<usize> for Uint<LIMBS> {
@@ -130,9 +192,74 @@ impl<const LIMBS: usize> ShlAssign<usize> for Uint<LIMBS> {
     }
 }
 
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Computes `self << shift`, returning a [`CtOption`] which `is_some`
+    /// only if `shift < Self::BITS`.
+    pub fn checked_shl(&self, shift: usize) -> CtOption<Self> {
+        CtOption::new(
+            self.shl(shift),
+            CtChoice::from_usize_lt(shift, Self::BITS).into(),
+        )
+    }
+
+    /// Computes `self << (shift % Self::BITS)`, returning the result along with
+    /// [`CtChoice::TRUE`] if `shift >= Self::BITS`.
+    pub const fn overflowing_shl(&self, shift: usize) -> (Self, CtChoice) {
+        (
+            self.shl(shift % Self::BITS),
+            CtChoice::from_usize_lt(shift, Self::BITS).not(),
+        )
+    }
+}
+
+impl<const LIMBS: usize> Shl<usize> for Wrapping<Uint<LIMBS>> {
+    type Output = Wrapping<Uint<LIMBS>>;
+
+    fn shl(self, rhs: usize) -> Wrapping<Uint<LIMBS>> {
+        Wrapping(self.0.shl(rhs))
+    }
+}
+
+impl<const LIMBS: usize> Shl<usize> for &Wrapping<Uint<LIMBS>> {
+    type Output = Wrapping<Uint<LIMBS>>;
+
+    fn shl(self, rhs: usize) -> Wrapping<Uint<LIMBS>> {
+        Wrapping(self.0.shl(rhs))
+    }
+}
+
+impl<const LIMBS: usize> ShlAssign<usize> for Wrapping<Uint<LIMBS>> {
+    fn shl_assign(&mut self, rhs: usize) {
+        *self = *self << rhs;
+    }
+}
+
+impl<const LIMBS: usize> Shl<usize> for Checked<Uint<LIMBS>> {
+    type Output = Checked<Uint<LIMBS>>;
+
+    fn shl(self, rhs: usize) -> Checked<Uint<LIMBS>> {
+        Checked(self.0.and_then(|lhs| lhs.checked_shl(rhs)))
+    }
+}
+
+impl<const LIMBS: usize> Shl<usize> for &Checked<Uint<LIMBS>> {
+    type Output = Checked<Uint<LIMBS>>;
+
+    fn shl(self, rhs: usize) -> Checked<Uint<LIMBS>> {
+        Checked(self.0.and_then(|lhs| lhs.checked_shl(rhs)))
+    }
+}
+
+impl<const LIMBS: usize> ShlAssign<usize> for Checked<Uint<LIMBS>> {
+    fn shl_assign(&mut self, rhs: usize) {
+        *self = *self << rhs;
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
-    use crate::{Limb, Uint, U128, U256};
+    use crate::{Checked, Limb, Uint, Wrapping, U128, U256};
 
     const N: U256 =
         U256::from_be_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141");
@@ -213,4 +340,71 @@ mod tests {
             (U128::ZERO, U128::ZERO)
         );
     }
+
+    #[test]
+    fn shl_assign_vartime() {
+        for n in 0..=U256::BITS + 1 {
+            let mut x = N;
+            x.shl_assign_vartime(n);
+            assert_eq!(x, N.shl_vartime(n));
+        }
+    }
+
+    #[test]
+    fn shl_wrapping_checked() {
+        for n in [0, 1, 64, U256::BITS - 1] {
+            assert_eq!(Wrapping(N) << n, Wrapping(N.shl_vartime(n)));
+            assert_eq!((Checked::new(N) << n).0.unwrap(), N.shl_vartime(n));
+        }
+
+        assert_eq!(Wrapping(N) << U256::BITS, Wrapping(U256::ZERO));
+        assert!(bool::from((Checked::new(N) << U256::BITS).0.is_none()));
+        assert!(bool::from(N.checked_shl(U256::BITS).is_none()));
+
+        let mut x = Checked::new(N);
+        x <<= 1;
+        x <<= U256::BITS - 1;
+        assert!(bool::from(x.0.is_some()));
+        x <<= U256::BITS + 1;
+        assert!(bool::from(x.0.is_none()));
+    }
+
+    #[test]
+    fn overflowing_shl() {
+        let (res, overflow) = N.overflowing_shl(1);
+        assert_eq!(res, N.shl_vartime(1));
+        assert!(!bool::from(overflow));
+
+        let (res, overflow) = N.overflowing_shl(U256::BITS + 1);
+        assert_eq!(res, N.shl_vartime(1));
+        assert!(bool::from(overflow));
+    }
+
+    #[test]
+    fn shl_wide() {
+        let x = (
+            U128::MAX.wrapping_sub(&U128::from_u8(5)),
+            U128::from_u64(0xdeadbeef),
+        );
+        for shift in 0..=2 * U128::BITS + 1 {
+            assert_eq!(
+                Uint::shl_wide(x, shift),
+                Uint::shl_vartime_wide(x, shift),
+                "shift = {shift}"
+            );
+        }
+    }
+
+    #[test]
+    fn rotl() {
+        let x = U128::from_u128(0x0123_4567_89ab_cdef_fedc_ba98_7654_3210);
+        for shift in 0..=2 * U128::BITS {
+            let expected = U128::from_u128(
+                0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128.rotate_left(shift as u32),
+            );
+            assert_eq!(x.rotl(shift), expected);
+            assert_eq!(x.rotl_vartime(shift), expected);
+            assert_eq!(x.rotl(shift).rotr(shift), x);
+        }
+    }
 }
@@ -1,7 +1,7 @@
 //! [`Uint`] addition operations.
 
 use super::Uint;
-use crate::{Checked, CheckedSub, CtChoice, Limb, Wrapping, Zero};
+use crate::{limb_slice, Checked, CheckedSub, CtChoice, Limb, Wrapping, Zero};
 use core::ops::{Sub, SubAssign};
 use subtle::CtOption;
 
@@ -22,6 +22,12 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         (Self { limbs }, borrow)
     }
 
+    /// Computes `self -= rhs + borrow` in place, returning the new borrow.
+    #[inline(always)]
+    pub fn sbb_assign(&mut self, rhs: &Self, borrow: Limb) -> Limb {
+        limb_slice::sub_n(&mut self.limbs, &rhs.limbs, borrow)
+    }
+
     /// Perform saturating subtraction, returning `ZERO` on underflow.
     pub const fn saturating_sub(&self, rhs: &Self) -> Self {
         let (res, underflow) = self.sbb(rhs, Limb::ZERO);
@@ -34,6 +40,13 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         self.sbb(rhs, Limb::ZERO).0
     }
 
+    /// Computes `self - rhs`, returning the result along with [`CtChoice::TRUE`]
+    /// if an underflow occurred.
+    pub const fn overflowing_sub(&self, rhs: &Self) -> (Self, CtChoice) {
+        let (res, borrow) = self.sbb(rhs, Limb::ZERO);
+        (res, CtChoice::from_mask(borrow.0))
+    }
+
     /// Perform wrapping subtraction, returning the truthy value as the second element of the tuple
     /// if an underflow has occurred.
     pub(crate) const fn conditional_wrapping_sub(
@@ -56,6 +69,31 @@ impl<const LIMBS: usize> CheckedSub<&Uint<LIMBS>> for Uint<LIMBS> {
     }
 }
 
+impl<const LIMBS: usize> Sub for Uint<LIMBS> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.sub(&rhs)
+    }
+}
+
+impl<const LIMBS: usize> Sub<&Uint<LIMBS>> for Uint<LIMBS> {
+    type Output = Self;
+
+    fn sub(self, rhs: &Self) -> Self {
+        self.checked_sub(rhs)
+            .expect("attempted to subtract with underflow")
+    }
+}
+
+impl<const LIMBS: usize> CheckedSub<&Wrapping<Uint<LIMBS>>> for Wrapping<Uint<LIMBS>> {
+    type Output = Self;
+
+    fn checked_sub(&self, rhs: &Self) -> CtOption<Self> {
+        self.0.checked_sub(&rhs.0).map(Wrapping)
+    }
+}
+
 impl<const LIMBS: usize> Sub for Wrapping<Uint<LIMBS>> {
     type Output = Self;
 
@@ -175,6 +213,18 @@ mod tests {
         assert_eq!(borrow, Limb::MAX);
     }
 
+    #[test]
+    fn sbb_assign() {
+        let mut x = U128::ZERO;
+        let borrow = x.sbb_assign(&U128::ONE, Limb::ZERO);
+        assert_eq!(x, U128::MAX);
+        assert_eq!(borrow, Limb::MAX);
+
+        let borrow = x.sbb_assign(&U128::ONE, borrow);
+        assert_eq!(x, U128::MAX.wrapping_sub(&U128::from_u8(2)));
+        assert_eq!(borrow, Limb::ZERO);
+    }
+
     #[test]
     fn saturating_sub_no_borrow() {
         assert_eq!(
@@ -212,4 +262,26 @@ mod tests {
         let result = U128::ZERO.checked_sub(&U128::ONE);
         assert!(!bool::from(result.is_some()));
     }
+
+    #[test]
+    fn sub_ok() {
+        assert_eq!(U128::ONE - U128::ONE, U128::ZERO);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to subtract with underflow")]
+    fn sub_underflow() {
+        let _ = U128::ZERO - U128::ONE;
+    }
+
+    #[test]
+    fn overflowing_sub() {
+        let (res, overflow) = U128::ONE.overflowing_sub(&U128::ONE);
+        assert_eq!(res, U128::ZERO);
+        assert!(!bool::from(overflow));
+
+        let (res, overflow) = U128::ZERO.overflowing_sub(&U128::ONE);
+        assert_eq!(res, U128::MAX);
+        assert!(bool::from(overflow));
+    }
 }
@@ -1,8 +1,10 @@
 //! Wrapping arithmetic.
 
-use crate::Zero;
+use crate::{Bounded, Encoding, Limb, UintLike, Zero};
 use core::fmt;
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+};
 
 #[cfg(feature = "rand_core")]
 use {crate::Random, rand_core::CryptoRngCore};
@@ -21,6 +23,61 @@ impl<T: Zero> Zero for Wrapping<T> {
     const ZERO: Self = Self(T::ZERO);
 }
 
+impl<T: Bounded> Bounded for Wrapping<T> {
+    const BITS: usize = T::BITS;
+    const BYTES: usize = T::BYTES;
+}
+
+impl<T: Encoding> Encoding for Wrapping<T> {
+    type Repr = T::Repr;
+
+    fn from_be_bytes(bytes: Self::Repr) -> Self {
+        Wrapping(T::from_be_bytes(bytes))
+    }
+
+    fn from_le_bytes(bytes: Self::Repr) -> Self {
+        Wrapping(T::from_le_bytes(bytes))
+    }
+
+    fn to_be_bytes(&self) -> Self::Repr {
+        self.0.to_be_bytes()
+    }
+
+    fn to_le_bytes(&self) -> Self::Repr {
+        self.0.to_le_bytes()
+    }
+}
+
+impl<T: UintLike> UintLike for Wrapping<T> {
+    fn bits_precision(&self) -> usize {
+        self.0.bits_precision()
+    }
+
+    fn nlimbs(&self) -> usize {
+        self.0.nlimbs()
+    }
+
+    fn zero_like(&self) -> Self {
+        Wrapping(self.0.zero_like())
+    }
+
+    fn one_like(&self) -> Self {
+        Wrapping(self.0.one_like())
+    }
+}
+
+impl<T: AsRef<[Limb]>> AsRef<[Limb]> for Wrapping<T> {
+    fn as_ref(&self) -> &[Limb] {
+        self.0.as_ref()
+    }
+}
+
+impl<T: From<u64>> From<u64> for Wrapping<T> {
+    fn from(n: u64) -> Self {
+        Wrapping(T::from(n))
+    }
+}
+
 impl<T: fmt::Display> fmt::Display for Wrapping<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
@@ -63,6 +120,18 @@ impl<T: ConstantTimeEq> ConstantTimeEq for Wrapping<T> {
     }
 }
 
+impl<T: ConstantTimeGreater> ConstantTimeGreater for Wrapping<T> {
+    fn ct_gt(&self, other: &Self) -> Choice {
+        self.0.ct_gt(&other.0)
+    }
+}
+
+impl<T: ConstantTimeLess> ConstantTimeLess for Wrapping<T> {
+    fn ct_lt(&self, other: &Self) -> Choice {
+        self.0.ct_lt(&other.0)
+    }
+}
+
 #[cfg(feature = "rand_core")]
 impl<T: Random> Random for Wrapping<T> {
     fn random(rng: &mut impl CryptoRngCore) -> Self {
@@ -90,11 +159,47 @@ impl<T: Serialize> Serialize for Wrapping<T> {
     }
 }
 
-#[cfg(all(test, feature = "serde"))]
+#[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
-    use crate::{Wrapping, U64};
+    use crate::{Encoding, Integer, NonZero, Wrapping, U128, U64};
+
+    /// Computes `1 + x + ... + x^(n-1)` using only the [`Integer`] trait.
+    fn geometric_sum<T: Integer>(x: T, n: usize) -> T {
+        let mut sum = T::ZERO;
+        let mut term = T::ONE;
+        for _ in 0..n {
+            sum = sum.wrapping_add(&term);
+            term = term.wrapping_mul(&x);
+        }
+        sum
+    }
+
+    #[test]
+    fn integer() {
+        let x = U128::from_u64(u64::MAX);
+        assert_eq!(geometric_sum(Wrapping(x), 5), Wrapping(geometric_sum(x, 5)));
+        assert_eq!(Wrapping::<U128>::MAX + Wrapping::ONE, Wrapping(U128::ZERO));
+        assert_eq!(
+            Wrapping::<U128>::from(7u64) << 2,
+            Wrapping(U128::from(28u8))
+        );
+        assert_eq!(Wrapping::<U128>::from(7u64) >> 2, Wrapping(U128::ONE));
+
+        let d = NonZero::new(Wrapping(U128::from(5u8))).unwrap();
+        assert_eq!(Wrapping(U128::from(17u8)) / d, Wrapping(U128::from(3u8)));
+        assert_eq!(Wrapping(U128::from(17u8)) % d, Wrapping(U128::from(2u8)));
+    }
+
+    #[test]
+    fn encoding() {
+        let x = Wrapping(U64::from_u64(0x0011223344556677));
+        assert_eq!(x.to_be_bytes(), x.0.to_be_bytes());
+        assert_eq!(Wrapping::<U64>::from_be_bytes(x.to_be_bytes()), x);
+        assert_eq!(Wrapping::<U64>::from_le_bytes(x.to_le_bytes()), x);
+    }
 
+    #[cfg(feature = "serde")]
     #[test]
     fn serde() {
         const TEST: Wrapping<U64> = Wrapping(U64::from_u64(0x0011223344556677));
@@ -105,6 +210,7 @@ mod tests {
         assert_eq!(TEST, deserialized);
     }
 
+    #[cfg(feature = "serde")]
     #[test]
     fn serde_owned() {
         const TEST: Wrapping<U64> = Wrapping(U64::from_u64(0x0011223344556677));
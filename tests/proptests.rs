@@ -2,7 +2,7 @@
 
 use crypto_bigint::{
     modular::runtime_mod::{DynResidue, DynResidueParams},
-    CtChoice, Encoding, Limb, NonZero, Word, U256,
+    CtChoice, Encoding, Limb, MultiExponentiateBoundedExp, NonZero, Word, U128, U256,
 };
 use num_bigint::BigUint;
 use num_integer::Integer;
@@ -10,6 +10,9 @@ use num_traits::identities::{One, Zero};
 use proptest::prelude::*;
 use std::mem;
 
+#[cfg(feature = "alloc")]
+use crypto_bigint::BoxedUint;
+
 /// Example prime number (NIST P-256 curve order)
 const P: U256 =
     U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
@@ -95,6 +98,91 @@ proptest! {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn set_bit(a in uint(), index in any::<u16>(), value in any::<bool>()) {
+        let mut a_bi = to_biguint(&a);
+
+        // Add a 50% probability of the index being out of range.
+        let index = (index as usize) % (U256::BITS * 2);
+        a_bi.set_bit(index as u64, value);
+
+        let expected = to_uint(a_bi);
+        let choice = if value { CtChoice::TRUE } else { CtChoice::FALSE };
+        assert_eq!(expected, a.set_bit(index, choice));
+        assert_eq!(expected, a.set_bit_vartime(index, value));
+    }
+
+    #[test]
+    fn clear_bit(a in uint(), index in any::<u16>()) {
+        let mut a_bi = to_biguint(&a);
+
+        // Add a 50% probability of the index being out of range.
+        let index = (index as usize) % (U256::BITS * 2);
+        a_bi.set_bit(index as u64, false);
+
+        let expected = to_uint(a_bi);
+        assert_eq!(expected, a.clear_bit(index));
+        assert_eq!(expected, a.clear_bit_vartime(index));
+    }
+
+    #[test]
+    fn count_ones(a in uint()) {
+        let a_bi = to_biguint(&a);
+        assert_eq!(a_bi.count_ones() as usize, a.count_ones());
+    }
+
+    #[test]
+    fn low_mask(k in any::<u16>()) {
+        // Add a 50% probability of the mask covering the whole integer.
+        let k = (k as usize) % (U256::BITS * 2);
+
+        let expected = to_uint((BigUint::one() << k) - BigUint::one());
+        assert_eq!(expected, U256::low_mask(k));
+        assert_eq!(expected, U256::low_mask_vartime(k));
+    }
+
+    #[test]
+    fn extract_bits(a in uint(), pos in any::<u16>(), len in 0..=Limb::BITS) {
+        let a_bi = to_biguint(&a);
+        let pos = (pos as usize) % (U256::BITS + Limb::BITS);
+
+        let mask = (BigUint::one() << len) - BigUint::one();
+        let expected = to_uint((a_bi >> pos) & mask).as_words()[0];
+        assert_eq!(expected, a.extract_bits(pos, len));
+        assert_eq!(expected, a.extract_bits_vartime(pos, len));
+    }
+
+    #[test]
+    fn insert_bits(a in uint(), pos in any::<u16>(), len in 0..=Limb::BITS, value in any::<Word>()) {
+        let a_bi = to_biguint(&a);
+        let pos = (pos as usize) % (U256::BITS + Limb::BITS);
+
+        let mask = (BigUint::one() << len) - BigUint::one();
+        let cleared = &a_bi - (&a_bi & (&mask << pos));
+        let expected = to_uint(cleared | ((BigUint::from(value) & mask) << pos));
+        assert_eq!(expected, a.insert_bits(pos, len, value));
+        assert_eq!(expected, a.insert_bits_vartime(pos, len, value));
+    }
+
+    #[test]
+    fn reverse_bits(a in uint()) {
+        let a_bi = to_biguint(&a);
+
+        let reversed: String = format!("{:0256b}", a_bi).chars().rev().collect();
+        let expected = to_uint(BigUint::parse_bytes(reversed.as_bytes(), 2).unwrap());
+        assert_eq!(expected, a.reverse_bits());
+    }
+
+    #[test]
+    fn swap_bytes(a in uint()) {
+        let a_bi = to_biguint(&a);
+
+        let mut bytes = a_bi.to_bytes_le();
+        bytes.resize(U256::BYTES, 0);
+        let expected = to_uint(BigUint::from_bytes_be(&bytes));
+        assert_eq!(expected, a.swap_bytes());
+    }
+
     #[test]
     fn wrapping_add(a in uint(), b in uint()) {
         let a_bi = to_biguint(&a);
@@ -185,6 +273,87 @@ proptest! {
         }
     }
 
+    #[test]
+    fn div_rem_vartime(a in uint(), b in uint(), shift in any::<u8>()) {
+        // Exercise divisors spanning any number of limbs
+        let b = b.shr_vartime(shift as usize);
+        let a_bi = to_biguint(&a);
+        let b_bi = to_biguint(&b);
+
+        if !b_bi.is_zero() {
+            let (expected_quo, expected_rem) = a_bi.div_rem(&b_bi);
+            let (actual_quo, actual_rem) = a.div_rem_vartime(&NonZero::new(b).unwrap());
+            assert_eq!(to_uint(expected_quo), actual_quo);
+            assert_eq!(to_uint(expected_rem), actual_rem);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed_div_rem(a in uint(), b in uint(), shift in any::<u8>()) {
+        let b = b.shr_vartime(shift as usize);
+
+        if b != U256::ZERO {
+            let (expected_quo, expected_rem) = a.div_rem(&NonZero::new(b).unwrap());
+
+            let a = BoxedUint::from_words(a.as_words());
+            let b = NonZero::new(BoxedUint::from_words(b.as_words())).unwrap();
+            let (actual_quo, actual_rem) = a.div_rem(&b);
+            assert_eq!(expected_quo.as_words(), actual_quo.as_words());
+            assert_eq!(expected_rem.as_words(), actual_rem.as_words());
+        }
+    }
+
+    #[test]
+    fn div_rem_mixed(a in uint(), b in uint(), shift in any::<u8>()) {
+        let b: U128 = b.shr_vartime(shift as usize).resize();
+        let a_bi = to_biguint(&a);
+        let b_bi = to_biguint(&b.resize());
+
+        if !b_bi.is_zero() {
+            let (expected_quo, expected_rem) = a_bi.div_rem(&b_bi);
+            let (actual_quo, actual_rem) = a.div_rem_mixed(&NonZero::new(b).unwrap());
+            assert_eq!(to_uint(expected_quo), actual_quo);
+            assert_eq!(to_uint(expected_rem), actual_rem.resize());
+        }
+    }
+
+    #[test]
+    fn square_wide(a in uint()) {
+        assert_eq!(a.square_wide(), a.mul_wide(&a));
+
+        let expected = to_biguint(&a) * to_biguint(&a);
+        let (lo, hi) = a.square_wide();
+        assert_eq!(to_uint(expected.clone()), lo);
+        assert_eq!(to_uint(expected >> U256::BITS), hi);
+    }
+
+    #[test]
+    fn mul_into(a in uint(), b in uint()) {
+        let b: U128 = b.resize();
+        let expected = to_biguint(&a) * to_biguint(&b.resize());
+        let overflow = expected.bits() > 128;
+        let expected: U128 = to_uint(expected).resize();
+
+        let (actual, actual_overflow) = a.mul_into(&b);
+        assert_eq!(expected, actual);
+        assert_eq!(overflow, bool::from(actual_overflow));
+    }
+
+    #[test]
+    fn rem_wide(lo in uint(), hi in uint(), m in uint(), shift in any::<u8>()) {
+        let m = m.shr_vartime(shift as usize);
+        let m_bi = to_biguint(&m);
+
+        if !m_bi.is_zero() {
+            let x_bi = (to_biguint(&hi) << U256::BITS) + to_biguint(&lo);
+            let expected = to_uint(x_bi % m_bi);
+            let (actual, is_some) = U256::const_rem_wide((lo, hi), &m);
+            assert!(bool::from(is_some));
+            assert_eq!(expected, actual);
+        }
+    }
+
     #[test]
     fn div_rem_limb(a in uint(), b in nonzero_limb()) {
         let a_bi = to_biguint(&a);
@@ -324,6 +493,24 @@ proptest! {
         assert_eq!(a, U256::from_be_bytes(bytes));
     }
 
+    #[test]
+    fn be_bytes_minimal(a in uint(), padding in 0usize..8) {
+        let mut out = [0u8; 32];
+        let len = a.to_be_bytes_minimal(&mut out);
+        if a == U256::ZERO {
+            assert_eq!(len, 0);
+        } else {
+            assert_eq!(out[..len], to_biguint(&a).to_bytes_be());
+        }
+        assert_eq!(U256::from_be_bytes_minimal(&out[..len]).unwrap(), a);
+
+        let mut padded = [0xffu8; 40];
+        let padded = &mut padded[..32 + padding];
+        assert!(bool::from(a.to_be_bytes_padded(padded)));
+        assert!(padded[..padding].iter().all(|&byte| byte == 0));
+        assert_eq!(U256::from_be_bytes_padded(padded).unwrap(), a);
+    }
+
     #[test]
     fn residue_pow(a in uint_mod_p(P), b in uint()) {
         let a_bi = to_biguint(&a);
@@ -357,6 +544,78 @@ proptest! {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn residue_multi_exponentiate(
+        a in uint_mod_p(P),
+        b in uint_mod_p(P),
+        e in uint(),
+        f in uint(),
+        exponent_bits in any::<u8>()
+    ) {
+        let p_bi = to_biguint(&P);
+        let mask = (U256::ONE << exponent_bits.into()).wrapping_sub(&U256::ONE);
+        let expected = to_uint(
+            to_biguint(&a).modpow(&to_biguint(&(e & mask)), &p_bi)
+                * to_biguint(&b).modpow(&to_biguint(&(f & mask)), &p_bi)
+                % &p_bi,
+        );
+
+        let params = DynResidueParams::new(&P);
+        let bases_and_exponents = [
+            (DynResidue::new(&a, params), e),
+            (DynResidue::new(&b, params), f),
+        ];
+
+        let actual = DynResidue::multi_exponentiate_bounded_exp(
+            &bases_and_exponents,
+            exponent_bits.into(),
+        );
+        assert_eq!(expected, actual.retrieve());
+
+        let actual = DynResidue::multi_exponentiate_bounded_exp_vartime(
+            &bases_and_exponents,
+            exponent_bits.into(),
+        );
+        assert_eq!(expected, actual.retrieve());
+
+        #[cfg(feature = "alloc")]
+        {
+            let actual = DynResidue::multi_exponentiate_bounded_exp_vartime(
+                bases_and_exponents.as_slice(),
+                exponent_bits.into(),
+            );
+            assert_eq!(expected, actual.retrieve());
+        }
+    }
+
+    #[test]
+    fn residue_mul_assign(a in uint(), b in uint(), m in uint()) {
+        let m = m | U256::ONE;
+        let (a, b) = (a.wrapping_rem(&m), b.wrapping_rem(&m));
+        let m_bi = to_biguint(&m);
+
+        let expected = to_uint((to_biguint(&a) * to_biguint(&b)) % m_bi);
+
+        let params = DynResidueParams::new(&m);
+        let mut a_m = DynResidue::new(&a, params);
+        a_m.montgomery_mul_assign(&DynResidue::new(&b, params));
+
+        assert_eq!(expected, a_m.retrieve());
+    }
+
+    #[test]
+    fn residue_square(a in uint(), m in uint()) {
+        let m = m | U256::ONE;
+        let a = a.wrapping_rem(&m);
+
+        let params = DynResidueParams::new(&m);
+        let a_m = DynResidue::new(&a, params);
+        assert_eq!(a_m.square(), a_m * a_m);
+
+        let expected = to_uint((to_biguint(&a) * to_biguint(&a)) % to_biguint(&m));
+        assert_eq!(expected, a_m.square().retrieve());
+    }
+
     #[test]
     fn residue_div_by_2(a in uint_mod_p(P)) {
         let a_bi = to_biguint(&a);